@@ -0,0 +1,54 @@
+/// Generates the solution registry that lets the runner call every day in-process.
+/// With the `in-process` feature, each `src/bin/<year>-<day>.rs` is included as a module of the runner binary,
+/// see `src/main.rs`. Without it, the registry stays empty and days run as separate binaries.
+use std::{env, fs, path::Path};
+
+include!("src/template/max_day.rs");
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=src/template/max_day.rs");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // solutions are named `<year>-<day>.rs`.
+    let mut puzzles: Vec<(u16, u8)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    let (year, day) = name.strip_suffix(".rs")?.split_once('-')?;
+                    if year.len() != 4 || day.len() != 2 {
+                        return None;
+                    }
+                    Some((year.parse().ok()?, day.parse().ok()?))
+                })
+                .filter(|(_, day)| (1..=max_day!()).contains(day))
+                .collect()
+        })
+        .unwrap_or_default();
+    puzzles.sort_unstable();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, day) in puzzles {
+        let path = bin_dir.join(format!("{year}-{day:02}.rs"));
+        let module = format!("puzzle_{year}_{day:02}");
+        modules.push_str(&format!(
+            "#[path = {:?}]\n#[allow(dead_code)]\nmod {module};\n",
+            path.display().to_string()
+        ));
+        entries.push_str(&format!(
+            "    Solution {{ puzzle: {module}::PUZZLE, run: {module}::run_parts }},\n"
+        ));
+    }
+
+    let registry = format!(
+        "use advent_of_code::template::registry::Solution;\n\n{modules}\npub static SOLUTIONS: &[Solution] = &[\n{entries}];\n"
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use advent_of_code::parse::{integer, parse_lines, ParseError};
+use nom::{character::complete::space1, sequence::separated_pair};
+
+advent_of_code::solution!(1);
+
+fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse_lines(input, separated_pair(integer, space1, integer))
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let mut v1 = Vec::with_capacity(128);
+    let mut v2 = Vec::with_capacity(128);
+    for (left, right) in parse(input)? {
+        v1.push(left);
+        v2.push(right);
+    }
+    v1.sort();
+    v2.sort();
+    let result = v1
+        .into_iter()
+        .zip(v2)
+        .fold(0u64, |acc, (i1, i2)| acc + i1.abs_diff(i2));
+
+    Ok(result)
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let mut v1 = Vec::with_capacity(128);
+    let mut hm = HashMap::new();
+    for (left, hashmap_key) in parse(input)? {
+        v1.push(left);
+
+        match hm.get_mut(&hashmap_key) {
+            None => {
+                hm.insert(hashmap_key, 1);
+            }
+            Some(old_value) => *old_value += 1,
+        }
+    }
+    let result = v1
+        .into_iter()
+        .fold(0, |acc, i1| acc + i1 * hm.get(&i1).unwrap_or(&0));
+    Ok(result)
+}
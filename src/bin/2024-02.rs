@@ -1,14 +1,19 @@
+use advent_of_code::parse::{integers, parse_lines, ParseError};
+
 advent_of_code::solution!(2);
 
-fn check_safety(line: &str) -> bool {
+fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_lines(input, integers)
+}
+
+fn check_safety(report: &[u32]) -> bool {
     let mut values = Vec::new();
-    for val in line.split_ascii_whitespace() {
-        let current_value = val.parse::<u8>().unwrap();
-        if values.is_empty() {
+    for &current_value in report {
+        let Some(&(last_value, _)) = values.last() else {
             values.push((current_value, 0));
             continue;
-        }
-        let last_inserted_diff = values.last().unwrap().0 as i32 - current_value as i32;
+        };
+        let last_inserted_diff = last_value as i32 - current_value as i32;
         if !(1..=3).contains(&last_inserted_diff.abs()) {
             return false;
         }
@@ -23,13 +28,8 @@ fn check_safety(line: &str) -> bool {
     true
 }
 
-fn check_safety_dampened(line: &str) -> bool {
-    let values: Vec<u32> = line
-        .split_whitespace()
-        .map(|v| v.parse().unwrap())
-        .collect();
-
-    is_safe(&values) || is_safe2(&values)
+fn check_safety_dampened(report: &[u32]) -> bool {
+    is_safe(report) || is_safe2(report)
 }
 
 fn is_safe(v: &[u32]) -> bool {
@@ -58,36 +58,15 @@ fn is_safe2(v: &[u32]) -> bool {
     false
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    Some(
-        input
-            .lines()
-            .fold(0, |acc, line| acc + check_safety(line) as u64),
-    )
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    Ok(parse(input)?
+        .iter()
+        .fold(0, |acc, report| acc + check_safety(report) as u64))
 }
 
 // taken / adapted from https://github.com/0xdea/aoc-2024-in-rust/blob/main/src/bin/02.rs
-pub fn part_two(input: &str) -> Option<u64> {
-    Some(
-        input
-            .lines()
-            .fold(0, |acc, line| acc + check_safety_dampened(line) as u64),
-    )
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4));
-    }
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    Ok(parse(input)?
+        .iter()
+        .fold(0, |acc, report| acc + check_safety_dampened(report) as u64))
 }
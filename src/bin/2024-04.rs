@@ -0,0 +1,47 @@
+use advent_of_code::{
+    geometry::{point, Direction8},
+    grid::{Grid, ParseGridError},
+};
+
+advent_of_code::solution!(4);
+
+fn count_xmas(grid: &Grid) -> u64 {
+    grid.find_all(&b'X')
+        .map(|(x, y)| {
+            Direction8::iter()
+                .filter(|direction| {
+                    grid.ray(x, y, direction.offset())
+                        .map(|position| grid[position])
+                        .take(4)
+                        .eq(*b"XMAS")
+                })
+                .count() as u64
+        })
+        .sum()
+}
+
+fn count_mas(grid: &Grid) -> u64 {
+    grid.find_all(&b'A')
+        .filter(|&(x, y)| {
+            let center = point(x as i64, y as i64);
+            Direction8::DIAGONALS
+                .iter()
+                .filter(|direction| {
+                    let (from, to) = (center - direction.offset(), center + direction.offset());
+                    grid.get(from.x, from.y) == Some(&b'M') && grid.get(to.x, to.y) == Some(&b'S')
+                })
+                .count()
+                == 2
+        })
+        .count() as u64
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseGridError> {
+    let grid = Grid::parse(input)?;
+    Ok(count_xmas(&grid))
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseGridError> {
+    let grid = Grid::parse(input)?;
+    Ok(count_mas(&grid))
+}
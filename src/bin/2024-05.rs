@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use advent_of_code::{
+    graph::{topological_sort, CycleError},
+    parse::{blank_line, integer, integers, lines_of, parse_all, ParseError},
+};
+use nom::{character::complete::char, sequence::separated_pair};
+
+advent_of_code::solution!(5);
+
+type Rules = HashMap<u64, Vec<u64>>;
+
+fn parse(input: &str) -> Result<(Rules, Vec<Vec<u64>>), ParseError> {
+    let rule = separated_pair(integer, char('|'), integer);
+    let (rule_list, updates) = parse_all(
+        input,
+        separated_pair(lines_of(rule), blank_line, lines_of(integers)),
+    )?;
+
+    let mut rules = HashMap::new();
+    for (pre, post) in rule_list {
+        match rules.get_mut(&pre) {
+            None => {
+                rules.insert(pre, vec![post]);
+            }
+            Some(existing) => existing.push(post),
+        }
+    }
+    Ok((rules, updates))
+}
+
+fn middle_page(update: &[u64]) -> u64 {
+    update[update.len() / 2]
+}
+
+fn check_rules_for_update(rules: &Rules, update: &[u64]) -> bool {
+    for (i, &page) in update.iter().enumerate().skip(1) {
+        let preceding_pages = &update[0..i];
+        let pages_that_follow = match rules.get(&page) {
+            None => {
+                continue;
+            }
+            Some(rules) => rules,
+        };
+        for &follwing_page in pages_that_follow.iter() {
+            for &preceding_pages in preceding_pages {
+                if preceding_pages == follwing_page {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Orders the pages of an update so that every rule between them holds.
+fn sort_incorrect(rules: &Rules, update: &[u64]) -> Result<Vec<u64>, CycleError<u64>> {
+    topological_sort(update.iter().copied(), |page| {
+        let following_pages = rules.get(page).map(Vec::as_slice).unwrap_or_default();
+        following_pages
+            .iter()
+            .copied()
+            .filter(|page| update.contains(page))
+            .collect::<Vec<_>>()
+    })
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let (rules, updates) = parse(input)?;
+    Ok(updates
+        .iter()
+        .map(|update| match check_rules_for_update(&rules, update) {
+            true => middle_page(update),
+            false => 0,
+        })
+        .sum())
+}
+
+pub fn part_two(input: &str) -> Result<u64, String> {
+    let (rules, updates) = parse(input).map_err(|e| e.to_string())?;
+    updates
+        .iter()
+        .map(|update| match check_rules_for_update(&rules, update) {
+            true => Ok(0),
+            false => sort_incorrect(&rules, update)
+                .map(|sorted| middle_page(&sorted))
+                .map_err(|e| e.to_string()),
+        })
+        .sum()
+}
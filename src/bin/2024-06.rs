@@ -0,0 +1,89 @@
+use advent_of_code::{
+    geometry::{point, Direction},
+    grid::Grid,
+    simulation::simulate,
+};
+
+advent_of_code::solution!(6, variants { 2 => [part_two, part_two_brute_force] });
+
+fn parse_input(input: &str) -> Result<(Grid, (usize, usize)), String> {
+    let grid = Grid::parse(input).map_err(|e| e.to_string())?;
+    let start = grid.find(&b'^').ok_or("expecting a guard `^` on the map")?;
+    Ok((grid, start))
+}
+
+/// Marks the path of the guard with `X`. Returns None if the guard gets stuck in a loop.
+fn color_path(mut grid: Grid, start: (usize, usize)) -> Option<Grid> {
+    grid[start] = b'X';
+
+    let guard = (point(start.0 as i64, start.1 as i64), Direction::Up);
+    let outcome = simulate(guard, |&(pos, dir)| {
+        // Keep moving until the guard leaves the area
+        let next = pos + dir.offset();
+        match grid.get_mut(next.x, next.y)? {
+            b'#' => Some((pos, dir.turn_right())),
+            cell => {
+                *cell = b'X';
+                Some((next, dir))
+            }
+        }
+    });
+
+    // The guard got stuck in a loop unless the simulation finished
+    outcome.is_finished().then_some(grid)
+}
+
+fn count_path(path: &Grid) -> usize {
+    path.find_all(&b'X').count()
+}
+
+const STUCK: &str = "the guard never leaves the map";
+
+pub fn part_one(input: &str) -> Result<u64, String> {
+    let (grid, start) = parse_input(input)?;
+    let path = color_path(grid, start).ok_or(STUCK)?;
+    Ok(count_path(&path) as u64)
+}
+
+pub fn part_two(input: &str) -> Result<u64, String> {
+    let (grid, start) = parse_input(input)?;
+    let path = color_path(grid.clone(), start).ok_or(STUCK)?;
+    let mut answer = 0;
+
+    // Only obstructions on the original path can change the route of the guard
+    for position in path.find_all(&b'X').filter(|&position| position != start) {
+        let mut test_grid = grid.clone();
+        test_grid[position] = b'#';
+        if color_path(test_grid, start).is_none() {
+            answer += 1;
+        }
+    }
+    Ok(answer)
+}
+
+pub fn part_two_brute_force(input: &str) -> Result<u64, String> {
+    let (grid, start) = parse_input(input)?;
+    let mut answer = 0;
+
+    // Brute force approach;)
+    // The new obstruction can't be placed at the guard's starting position
+    for position in grid.positions().filter(|&position| position != start) {
+        let mut test_grid = grid.clone();
+        test_grid[position] = b'#';
+        if color_path(test_grid, start).is_none() {
+            answer += 1;
+        }
+    }
+    Ok(answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_two_brute_force() {
+        let result = part_two_brute_force(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(6));
+    }
+}
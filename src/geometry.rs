@@ -0,0 +1,237 @@
+/// Points, vectors and directions for puzzles that move around a plane.
+/// Follows the orientation of puzzle input: `x` grows to the right and `y` grows downwards, so [`Direction::Up`]
+/// decreases `y`. Points and vectors are `nalgebra` types, so they support the usual arithmetic.
+use nalgebra::{Point2 as NPoint2, Vector2};
+
+/// A position on the plane.
+pub type Point2 = NPoint2<i64>;
+
+/// A displacement between two positions, e.g. one step in a [`Direction`].
+pub type Vec2 = Vector2<i64>;
+
+/// Creates a [`Point2`] from its coordinates.
+pub const fn point(x: i64, y: i64) -> Point2 {
+    Point2::new(x, y)
+}
+
+/// The number of orthogonal steps between two points.
+pub fn manhattan(a: Point2, b: Point2) -> i64 {
+    (a.x - b.x).abs() + (a.y - b.y).abs()
+}
+
+/// The number of steps between two points if diagonal steps are allowed.
+pub fn chebyshev(a: Point2, b: Point2) -> i64 {
+    (a.x - b.x).abs().max((a.y - b.y).abs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Iterates over all directions, clockwise starting upwards.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Parses an arrow like `^` or a letter like `U`.
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'^' | b'U' | b'N' => Some(Self::Up),
+            b'>' | b'R' | b'E' => Some(Self::Right),
+            b'v' | b'D' | b'S' => Some(Self::Down),
+            b'<' | b'L' | b'W' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// Rotates by 90 degrees counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// One step in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(0, -1),
+            Self::Right => Vec2::new(1, 0),
+            Self::Down => Vec2::new(0, 1),
+            Self::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The four diagonal directions, clockwise starting up and to the right.
+    pub const DIAGONALS: [Self; 4] = [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    /// Iterates over all directions, clockwise starting upwards.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Rotates by 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Rotates by 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// One step in this direction, diagonal steps move along both axes.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(0, -1),
+            Self::UpRight => Vec2::new(1, -1),
+            Self::Right => Vec2::new(1, 0),
+            Self::DownRight => Vec2::new(1, 1),
+            Self::Down => Vec2::new(0, 1),
+            Self::DownLeft => Vec2::new(-1, 1),
+            Self::Left => Vec2::new(-1, 0),
+            Self::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Self::Up,
+            Direction::Right => Self::Right,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{chebyshev, manhattan, point, Direction, Direction8, Vec2};
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(manhattan(point(1, 2), point(4, -2)), 7);
+        assert_eq!(chebyshev(point(1, 2), point(4, -2)), 4);
+        assert_eq!(manhattan(point(3, 3), point(3, 3)), 0);
+    }
+
+    #[test]
+    fn moves_points() {
+        let start = point(2, 2);
+        assert_eq!(start + Direction::Up.offset(), point(2, 1));
+        assert_eq!(start + Direction8::DownLeft.offset() * 2, point(0, 4));
+        assert_eq!(point(5, 1) - start, Vec2::new(3, -1));
+    }
+
+    #[test]
+    fn rotates_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        for direction in Direction::iter() {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+        }
+
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpRight.reverse(), Direction8::DownLeft);
+        for direction in Direction8::iter() {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+            assert_eq!(
+                direction.is_diagonal(),
+                Direction8::DIAGONALS.contains(&direction)
+            );
+        }
+    }
+
+    #[test]
+    fn converts_directions() {
+        assert_eq!(Direction::from_byte(b'^'), Some(Direction::Up));
+        assert_eq!(Direction::from_byte(b'L'), Some(Direction::Left));
+        assert_eq!(Direction::from_byte(b'.'), None);
+        assert_eq!(Direction8::from(Direction::Down), Direction8::Down);
+        assert_eq!(
+            Direction::iter().map(Direction8::from).collect::<Vec<_>>(),
+            Direction8::iter()
+                .filter(|d| !d.is_diagonal())
+                .collect::<Vec<_>>()
+        );
+    }
+}
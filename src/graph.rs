@@ -0,0 +1,399 @@
+/// Search and ordering algorithms for graphs that are given as closures instead of data structures.
+/// Nodes can be any type that can be hashed, e.g. grid positions or puzzle states. The neighbours of a node are
+/// computed on demand by a `successors` closure, so graphs don't need to be built before they can be searched.
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Orders `nodes` so that every node comes before its successors.
+/// Successors that are not in `nodes` are ordered as well, filter them in `successors` to leave them out.
+/// Fails with one of the nodes on a cycle if there is no such order.
+pub fn topological_sort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Result<Vec<N>, CycleError<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut finished = HashSet::new();
+    let mut order = vec![];
+
+    for root in nodes {
+        if finished.contains(&root) {
+            continue;
+        }
+
+        // Depth-first search that keeps the path to the current node to detect back edges
+        let mut on_path = HashSet::from([root.clone()]);
+        let mut stack = vec![(root.clone(), successors(&root).into_iter())];
+
+        while let Some((node, remaining)) = stack.last_mut() {
+            match remaining.next() {
+                Some(next) if on_path.contains(&next) => return Err(CycleError { node: next }),
+                Some(next) if finished.contains(&next) => {}
+                Some(next) => {
+                    on_path.insert(next.clone());
+                    let next_successors = successors(&next).into_iter();
+                    stack.push((next, next_successors));
+                }
+                None => {
+                    on_path.remove(node);
+                    finished.insert(node.clone());
+                    order.push(node.clone());
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
+
+/// Finds a path with the fewest steps from `start` to a node that satisfies `is_goal`.
+/// The path starts with `start` and ends with the goal.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(build_path(&parents, node));
+        }
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from `start` to every node that can be reached from it.
+pub fn bfs_distances<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Finds a cheapest path from `start` to a node that satisfies `is_goal`, returns it with its cost.
+/// `successors` yields the neighbours of a node with the cost of the step to them, which must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores nodes with a low `heuristic` first.
+/// The heuristic estimates the remaining cost to a goal and must never overestimate it, e.g. [`crate::geometry::manhattan`]
+/// on a grid with steps that cost at least 1.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = BinaryHeap::from([Reverse(Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    })]);
+
+    while let Some(Reverse(Candidate { cost, node, .. })) = queue.pop() {
+        // Skip entries that were queued before a cheaper path to the node was found
+        if cost > costs[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((build_path(&parents, node), cost));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), Some(node.clone()));
+            queue.push(Reverse(Candidate {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            }));
+        }
+    }
+    None
+}
+
+/// Groups `nodes` into sets of nodes that are connected to each other.
+/// `neighbours` should be symmetric, the components contain nodes that are reached from `nodes` as well.
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+
+    for root in nodes {
+        if !seen.insert(root.clone()) {
+            continue;
+        }
+        let mut component = vec![];
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            for next in neighbours(&node) {
+                if seen.insert(next.clone()) {
+                    stack.push(next);
+                }
+            }
+            component.push(node);
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// Follows the parents from `goal` back to the start.
+fn build_path<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// A node queued by [`astar`], ordered by its costs alone so nodes don't need to implement [`Ord`].
+struct Candidate<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Prefer the deeper candidate on ties, it is closer to a goal
+        self.estimate
+            .cmp(&other.estimate)
+            .then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when nodes can't be ordered because they depend on each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N> {
+    /// A node on the cycle.
+    pub node: N,
+}
+
+impl<N: Debug> Error for CycleError<N> {}
+
+impl<N: Debug> Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the graph has a cycle through {:?}", self.node)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use nom::{character::complete::char, sequence::separated_pair};
+
+    use super::{
+        astar, bfs, bfs_distances, connected_components, dijkstra, topological_sort, CycleError,
+    };
+    use crate::{
+        day,
+        geometry::{manhattan, point, Direction, Point2},
+        grid::Grid,
+        parse::{blank_line, integer, integers, lines_of, parse_all},
+        template::{read_file, Puzzle},
+    };
+
+    type Rules = HashMap<u8, Vec<u8>>;
+
+    fn page_rules() -> (Rules, Vec<Vec<u8>>) {
+        let input = read_file("examples", Puzzle::new(2024, day!(5)));
+        let rule = separated_pair(integer, char('|'), integer);
+        let (rule_list, updates) = parse_all(
+            &input,
+            separated_pair(lines_of(rule), blank_line, lines_of(integers)),
+        )
+        .unwrap();
+
+        let mut rules = Rules::new();
+        for (before, after) in rule_list {
+            rules.entry(before).or_default().push(after);
+        }
+        (rules, updates)
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let (rules, updates) = page_rules();
+        let sort = |update: &Vec<u8>| {
+            topological_sort(update.clone(), |page| {
+                let after = rules.get(page).cloned().unwrap_or_default();
+                after.into_iter().filter(|page| update.contains(page))
+            })
+        };
+
+        assert_eq!(sort(&updates[0]), Ok(vec![75, 47, 61, 53, 29]));
+        assert_eq!(sort(&updates[3]), Ok(vec![97, 75, 47, 61, 53]));
+        assert_eq!(sort(&updates[4]), Ok(vec![61, 29, 13]));
+        assert_eq!(sort(&updates[5]), Ok(vec![97, 75, 47, 29, 13]));
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let edges = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![1]), (4, vec![1])]);
+        let result = topological_sort([4], |node| edges[node].clone());
+        assert!(matches!(result, Err(CycleError { node: 1..=3 })));
+
+        let edges = HashMap::from([(1, vec![2, 3]), (2, vec![3]), (3, vec![])]);
+        assert_eq!(
+            topological_sort([3, 1], |node| edges[node].clone()),
+            Ok(vec![1, 2, 3])
+        );
+    }
+
+    fn maze() -> Grid {
+        Grid::parse("S..#\n.#..\n...#\n#..E").unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+        Direction::iter()
+            .map(move |direction| position + direction.offset())
+            .filter(|next| grid.get(next.x, next.y).is_some_and(|&cell| cell != b'#'))
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let grid = maze();
+        let goal = point(3, 3);
+
+        let path = bfs(point(0, 0), |&p| open_neighbours(&grid, p), |&p| p == goal).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&point(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+        assert_eq!(
+            bfs(
+                point(0, 0),
+                |&p| open_neighbours(&grid, p),
+                |&p| p == point(3, 0)
+            ),
+            None
+        );
+
+        let distances = bfs_distances(point(0, 0), |&p| open_neighbours(&grid, p));
+        assert_eq!(distances[&goal], 6);
+        assert_eq!(distances.len(), 12);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        let grid = maze();
+        let goal = point(3, 3);
+        // Steps onto the left column cost more, so the cheapest path takes the top row
+        let weighted = |&p: &Point2| {
+            open_neighbours(&grid, p)
+                .map(|next| (next, if next.x == 0 { 5 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let (path, cost) = dijkstra(point(0, 0), weighted, |&p| p == goal).unwrap();
+        assert_eq!(cost, 6);
+        assert_eq!(path[..3], [point(0, 0), point(1, 0), point(2, 0)]);
+
+        let (_, astar_cost) = astar(
+            point(0, 0),
+            weighted,
+            |&p| manhattan(p, goal),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(astar_cost, cost);
+    }
+
+    #[test]
+    fn groups_components() {
+        let grid = Grid::parse("aab\nbab\nbbc").unwrap();
+        let neighbours = |&(x, y): &(usize, usize)| {
+            grid.neighbours_4(x, y)
+                .filter(|&n| grid[n] == grid[(x, y)])
+                .collect::<Vec<_>>()
+        };
+
+        let mut sizes: Vec<_> = connected_components(grid.positions(), neighbours)
+            .iter()
+            .map(Vec::len)
+            .collect();
+        sizes.sort();
+        assert_eq!(sizes, [1, 2, 3, 3]);
+    }
+}
@@ -0,0 +1,444 @@
+/// A dense two-dimensional grid for map-like puzzle inputs.
+/// Positions are `(x, y)` pairs, `x` grows to the right and `y` grows downwards. Accessors that take
+/// signed coordinates are bounds-checked, so neighbours can be computed without checking for underflow.
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use crate::geometry::{Direction, Direction8, Vec2};
+
+/// A rectangular grid of cells, stored row by row. Parsed grids default to one byte per cell.
+/// A grid always has at least one cell, so its width and height are never zero.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T = u8> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid<u8> {
+    /// Parses a grid with one row per line and one byte per cell.
+    pub fn parse(input: &str) -> Result<Self, ParseGridError> {
+        Self::parse_with(input, |byte| byte)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `fill`.
+    ///
+    /// # Panics
+    /// If `width` or `height` is zero, like parsed grids every grid has at least one cell.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            width > 0 && height > 0,
+            "expecting a grid with at least one cell, got {width}x{height}"
+        );
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid with one row per line, converting every byte to a cell with `cell`.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(u8) -> T) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            if y == 0 {
+                width = line.len();
+            } else if line.len() != width {
+                return Err(ParseGridError::RaggedRow {
+                    row: y,
+                    expected: width,
+                    found: line.len(),
+                });
+            }
+            cells.extend(line.bytes().map(&mut cell));
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a grid from its rows, which must all have the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseGridError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseGridError::RaggedRow {
+                    row: y,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether a position lies within the grid.
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        (0..self.width as i64).contains(&x) && (0..self.height as i64).contains(&y)
+    }
+
+    /// Converts a position to unsigned coordinates if it lies within the grid.
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub fn checked_position(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        self.contains(x, y).then_some((x as usize, y as usize))
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        let (x, y) = self.checked_position(x, y)?;
+        Some(&self[(x, y)])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        let (x, y) = self.checked_position(x, y)?;
+        Some(&mut self[(x, y)])
+    }
+
+    /// Returns the cell at a position, wrapping around the edges of the grid in both directions.
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self[(x, y)]
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells of the grid along with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell that equals `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// The positions of all cells that equal `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// The orthogonal neighbours of a position that lie within the grid.
+    pub fn neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, Direction::iter().map(Direction::offset))
+    }
+
+    /// The orthogonal and diagonal neighbours of a position that lie within the grid.
+    pub fn neighbours_8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, Direction8::iter().map(Direction8::offset))
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: impl Iterator<Item = Vec2> + 'static,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.filter_map(move |offset| {
+            self.checked_position(x as i64 + offset.x, y as i64 + offset.y)
+        })
+    }
+
+    /// The positions from `(x, y)` in steps of `step` until the edge of the grid, starting with `(x, y)`.
+    /// Steps are usually the offset of a direction, e.g. `Direction8::DownRight.offset()`.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn ray(&self, x: usize, y: usize, step: Vec2) -> impl Iterator<Item = (usize, usize)> + '_ {
+        iter::successors(self.checked_position(x as i64, y as i64), move |&(x, y)| {
+            self.checked_position(x as i64 + step.x, y as i64 + step.y)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The diagonals running down and to the right, starting with the one in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+        let step = Direction8::DownRight.offset();
+        starts.map(move |(x, y)| self.ray(x, y, step).map(|position| &self[position]))
+    }
+
+    /// The diagonals running down and to the left, starting with the one in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(|y| (self.width - 1, y)));
+        let step = Direction8::DownLeft.offset();
+        starts.map(move |(x, y)| self.ray(x, y, step).map(|position| &self[position]))
+    }
+
+    /// Creates a grid of the same size with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Draws the grid with one character per cell, one line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < self.width,
+            "x {x} is out of bounds of width {}",
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            x < self.width,
+            "x {x} is out of bounds of width {}",
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&byte| byte as char))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&c| c))
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Error for ParseGridError {}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("expecting a grid with at least one cell"),
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expecting every row to be {expected} cells wide, row {row} has {found}"
+            ),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, ParseGridError};
+    use crate::geometry::Direction;
+
+    const INPUT: &str = "abc\ndef\n";
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a u8>) -> String {
+        cells.map(|&b| b as char).collect()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], b'f');
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]),
+            Ok(Grid::parse_with("12\n34", |b| b - b'0').unwrap())
+        );
+
+        let digits = Grid::parse_with("12\n34", |b| b - b'0').unwrap();
+        assert_eq!(digits[(1, 1)], 4);
+        assert_eq!(
+            digits.map(|&d| char::from(b'0' + d * 2)).to_string(),
+            "24\n68"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_grids() {
+        assert_eq!(Grid::parse(""), Err(ParseGridError::Empty));
+        assert_eq!(Grid::<u8>::from_rows(vec![]), Err(ParseGridError::Empty));
+        assert_eq!(
+            Grid::<u8>::from_rows(vec![vec![], vec![]]),
+            Err(ParseGridError::Empty)
+        );
+        assert_eq!(Grid::parse("\n\n"), Err(ParseGridError::Empty));
+        assert_eq!(
+            Grid::parse("abc\nde"),
+            Err(ParseGridError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_empty_grids() {
+        Grid::new(0, 3, 0);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let mut grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.get(0, 0), Some(&b'a'));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.get_wrapping(-1, 2), &b'c');
+        assert_eq!(grid.get_wrapping(4, -1), &b'e');
+
+        *grid.get_mut(1, 1).unwrap() = b'#';
+        assert_eq!(grid.to_string(), "abc\nd#f");
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbours_8(2, 0).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 1), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = Grid::parse("a.a\n.a.").unwrap();
+        assert_eq!(grid.find(&b'a'), Some((0, 0)));
+        assert_eq!(
+            grid.find_all(&b'a').collect::<Vec<_>>(),
+            vec![(0, 0), (2, 0), (1, 1)]
+        );
+        assert_eq!(grid.find(&b'#'), None);
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.row(1), b"def");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(collect(grid.column(2)), "cf");
+        assert_eq!(
+            grid.columns().map(collect).collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals().map(collect).collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals().map(collect).collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+        assert_eq!(
+            grid.ray(2, 0, Direction::Left.offset()).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 0), (0, 0)]
+        );
+    }
+}
@@ -1,3 +1,8 @@
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod parse;
+pub mod simulation;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
@@ -1,79 +1,235 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, verify,
+};
+use advent_of_code::template::registry::Solution;
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, Puzzle};
 #[cfg(feature = "today")]
 use std::process;
 
+/// Counts the allocations of in-process solutions for `cargo time --memory`.
+/// DHAT brings its own global allocator.
+#[cfg(not(feature = "dhat-heap"))]
+#[global_allocator]
+static ALLOC: advent_of_code::template::alloc::CountingAllocator =
+    advent_of_code::template::alloc::CountingAllocator;
+
+/// Every scaffolded solution, compiled into this binary by `build.rs` with the `in-process` feature.
+/// Without it, a day that does not compile only affects itself as every day runs as a separate binary.
+/// Not available with DHAT enabled as every solution declares its own global allocator then.
+#[cfg(all(feature = "in-process", not(any(test, feature = "dhat-heap"))))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+#[cfg(all(feature = "in-process", not(any(test, feature = "dhat-heap"))))]
+fn solutions() -> &'static [Solution] {
+    solutions::SOLUTIONS
+}
+
+#[cfg(not(all(feature = "in-process", not(any(test, feature = "dhat-heap")))))]
+fn solutions() -> &'static [Solution] {
+    &[]
+}
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        days_in_year, default_year,
+        input::InputSource,
+        runner::{Limits, Statistic},
+        Day, Puzzle, PuzzleFromStrError,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
+        },
+        Examples {
+            puzzle: Puzzle,
+            overwrite: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            variants: bool,
+            input: InputSource,
         },
         All {
+            year: u16,
             release: bool,
+            jobs: usize,
+            limits: Limits,
         },
         Time {
+            year: u16,
             all: bool,
             day: Option<Day>,
             store: bool,
+            statistic: Statistic,
+            memory: bool,
+            limits: Limits,
+            compare: Option<f64>,
+        },
+        TimeHistory {
+            puzzle: Puzzle,
+        },
+        Verify {
+            year: u16,
+            day: Option<Day>,
+            release: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Parses `--timeout <seconds>` and `--memory-limit <megabytes>`.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, pico_args::Error> {
+        Ok(Limits {
+            timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            memory: args.opt_value_from_fn("--memory-limit", parse_memory_limit)?,
+        })
+    }
+
+    /// Parses a timeout in seconds, which must be finite and not negative.
+    fn parse_timeout(value: &str) -> Result<Duration, String> {
+        let seconds = value.parse::<f64>().map_err(|e| e.to_string())?;
+        Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+    }
+
+    /// Parses a memory limit in megabytes and returns it in bytes.
+    fn parse_memory_limit(value: &str) -> Result<u64, String> {
+        let megabytes = value.parse::<u64>().map_err(|e| e.to_string())?;
+        megabytes
+            .checked_mul(1024 * 1024)
+            .ok_or_else(|| format!("{megabytes} MB is too large"))
+    }
+
+    /// Checks that the optional day is part of the event in `year`.
+    fn check_day(year: u16, day: Option<Day>) -> Result<Option<Day>, PuzzleFromStrError> {
+        match day {
+            Some(day) if Puzzle::try_new(year, day).is_none() => {
+                Err(PuzzleFromStrError::DayOutOfRange {
+                    year,
+                    days: days_in_year(year),
+                })
+            }
+            _ => Ok(day),
+        }
+    }
+
+    /// Parses `--input <path>`, `--stdin` and `--example`.
+    fn parse_input(args: &mut pico_args::Arguments) -> Result<InputSource, pico_args::Error> {
+        Ok(match args.opt_value_from_str("--input")? {
+            Some(path) => InputSource::File(path),
+            None if args.contains("--stdin") => InputSource::Stdin,
+            None if args.contains("--example") => InputSource::Example,
+            None => InputSource::Puzzle,
+        })
+    }
+
+    /// Parses `--year <year>`, defaulting to `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<u16, pico_args::Error> {
+        Ok(args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(default_year))
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                limits: parse_limits(&mut args)?,
+            },
+            Some("time") if args.contains("--history") => AppArguments::TimeHistory {
+                puzzle: args.free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let statistic = args.opt_value_from_str("--statistic")?.unwrap_or_default();
+                let memory = args.contains("--memory");
+                let limits = parse_limits(&mut args)?;
+
+                // limits are enforced by running days as child processes, memory is only measured in-process.
+                if memory && limits.is_set() {
+                    return Err(
+                        "--memory can not be combined with --timeout or --memory-limit.".into(),
+                    );
+                }
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let compare = args.contains("--compare").then_some(threshold);
+
+                let year = parse_year(&mut args)?;
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: check_day(year, args.opt_free_from_str()?)?,
                     store,
+                    statistic,
+                    memory,
+                    limits,
+                    compare,
+                }
+            }
+            Some("verify") => {
+                let year = parse_year(&mut args)?;
+
+                AppArguments::Verify {
+                    year,
+                    release: args.contains("--release"),
+                    day: check_day(year, args.opt_free_from_str()?)?,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
+                puzzle: args.free_from_str()?,
+                overwrite: args.contains("--overwrite"),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let submit = args.opt_value_from_str("--submit")?;
+                let input = parse_input(&mut args)?;
+
+                if submit.is_some() && input != InputSource::Puzzle {
+                    return Err("answers can only be submitted for the puzzle input.".into());
+                }
+
+                AppArguments::Solve {
+                    puzzle: args.free_from_str()?,
+                    release: args.contains("--release"),
+                    submit,
+                    dhat: args.contains("--dhat"),
+                    variants: args.contains("--variants"),
+                    input,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -96,44 +252,81 @@ mod args {
 }
 
 fn main() {
+    #[cfg(not(feature = "dhat-heap"))]
+    ALLOC.mark_installed();
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All {
+                year,
+                release,
+                jobs,
+                limits,
+            } => all::handle(year, release, jobs, limits, solutions()),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                statistic,
+                memory,
+                limits,
+                compare,
+            } => time::handle(
+                year,
                 day,
+                all,
+                store,
+                statistic,
+                memory,
+                limits,
+                compare,
+                solutions(),
+            ),
+            AppArguments::TimeHistory { puzzle } => time::handle_history(puzzle),
+            AppArguments::Verify { year, day, release } => {
+                verify::handle(year, day, release, solutions());
+            }
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Examples { puzzle, overwrite } => examples::handle(puzzle, overwrite),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
+                    examples::handle(puzzle, false);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                variants,
+                input,
+            } => solve::handle(puzzle, release, dhat, submit, variants, &input),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        let puzzle = Puzzle::of_default_year(day);
+                        scaffold::handle(puzzle, false);
+                        download::handle(puzzle);
+                        examples::handle(puzzle, false);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run on the days of this year's event \
+                            in december. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,371 @@
+/// Parsers for the shapes puzzle inputs usually come in, built on `nom`.
+/// The parsers compose with any other `nom` parser. Run them with [`parse_all`] or [`parse_lines`], which turn
+/// failures into a [`ParseError`] that points at the line and column of the malformed input.
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use nom::{
+    branch::alt,
+    character::complete::{
+        alphanumeric1, char, digit1, line_ending, multispace0, one_of, space0, space1,
+    },
+    combinator::{all_consuming, map_res, opt, recognize, value},
+    error::{Error as NomError, ErrorKind},
+    multi::separated_list1,
+    sequence::{separated_pair, terminated},
+    IResult, Parser,
+};
+
+use crate::grid::{Grid, ParseGridError};
+
+/// An integer with an optional sign, e.g. `42` or `-7`. Unsigned types reject a `-` sign.
+pub fn integer<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize((opt(one_of("+-")), digit1)), str::parse).parse(input)
+}
+
+/// Integers separated by whitespace or commas, e.g. `7 6 4` or `75,47, -61`.
+pub fn integers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    let separator = alt((recognize((space0, char(','), space0)), space1));
+    separated_list1(separator, integer).parse(input)
+}
+
+/// A name made of letters and digits, e.g. a key like `abc`.
+pub fn word(input: &str) -> IResult<&str, &str> {
+    alphanumeric1(input)
+}
+
+/// A key and its values separated by a colon, e.g. `190: 10 19` with `key_values(integer, integers)`.
+pub fn key_values<'a, K, V>(
+    key: impl Parser<&'a str, Output = K, Error = NomError<&'a str>>,
+    values: impl Parser<&'a str, Output = V, Error = NomError<&'a str>>,
+) -> impl Parser<&'a str, Output = (K, V), Error = NomError<&'a str>> {
+    separated_pair(key, (space0, char(':'), space0), values)
+}
+
+/// Consecutive lines that all match `line`.
+pub fn lines_of<'a, O>(
+    line: impl Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>> {
+    separated_list1(line_ending, line)
+}
+
+/// The empty line between two sections of an input.
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    value((), (line_ending, space0, line_ending)).parse(input)
+}
+
+/// Sections separated by blank lines that all match `section`, e.g. `sections(lines_of(integer))`.
+/// For sections of different shapes, combine their parsers with [`blank_line`], e.g. in a `separated_pair`.
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>> {
+    separated_list1(blank_line, section)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses the whole input with `parser`, only whitespace may follow what it parsed.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+) -> Result<O, ParseError> {
+    all_consuming(terminated(parser, multispace0))
+        .parse(input)
+        .map(|(_, output)| output)
+        .map_err(|e| ParseError::from_nom(input, e))
+}
+
+/// Parses every line of the input with `parser`, only whitespace may follow what it parsed on a line.
+/// Like [`parse_all`], trailing whitespace of the input is ignored, including empty lines at its end.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+) -> Result<Vec<O>, ParseError> {
+    input
+        .trim_end()
+        .lines()
+        .map(|line| match parser.parse(line) {
+            Ok((rest, output)) if rest.trim().is_empty() => Ok(output),
+            Ok((rest, _)) => Err(ParseError::at(
+                input,
+                rest,
+                unexpected(rest, ErrorKind::Eof),
+            )),
+            Err(e) => Err(ParseError::from_nom(input, e)),
+        })
+        .collect()
+}
+
+/// Extracts every integer of the input and ignores the text around them, e.g. `[3, -4]` from `x=3, y=-4`.
+/// A `-` directly before a number is read as its sign unless `T` is unsigned.
+pub fn numbers<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+    let mut numbers = vec![];
+    let mut rest = input;
+
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let signed = start > 0 && rest.as_bytes()[start - 1] == b'-';
+        let token_start = if signed { start - 1 } else { start };
+        let (after, digits) = digit1::<&str, NomError<&str>>(&rest[start..])
+            .map_err(|e| ParseError::from_nom(input, e))?;
+
+        let number = rest[token_start..start + digits.len()]
+            .parse()
+            .or_else(|_| digits.parse())
+            .map_err(|_| {
+                ParseError::at(
+                    input,
+                    &rest[token_start..],
+                    format!("`{digits}` is out of range"),
+                )
+            })?;
+
+        numbers.push(number);
+        rest = after;
+    }
+
+    Ok(numbers)
+}
+
+/// Parses a grid with one row per line, converting every character with `cell`.
+/// Fails on characters that `cell` does not convert and on rows of different widths, counted in characters.
+/// Empty lines at the end of the input are ignored.
+pub fn grid<T>(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+    let rows = input
+        .trim_end_matches(['\n', '\r'])
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(index, c)| {
+                    cell(c).ok_or_else(|| {
+                        ParseError::at(input, &line[index..], format!("unexpected `{c}`"))
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Grid::from_rows(rows).map_err(|e| match e {
+        ParseGridError::Empty => ParseError::at(input, input, e.to_string()),
+        ParseGridError::RaggedRow { row, .. } => {
+            let line = input.lines().nth(row).unwrap_or_default();
+            ParseError::at(input, line, e.to_string())
+        }
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing puzzle input, pointing at the position of the malformed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// The column of the error in characters, starting at 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error at the start of `rest`, which must be a slice of `input`.
+    fn at(input: &str, rest: &str, message: String) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message,
+        }
+    }
+
+    fn from_nom(input: &str, error: nom::Err<NomError<&str>>) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Self::at(input, e.input, unexpected(e.input, e.code))
+            }
+            nom::Err::Incomplete(_) => Self::at(
+                input,
+                &input[input.len()..],
+                "unexpected end of input".into(),
+            ),
+        }
+    }
+}
+
+/// Describes the token at the start of `rest` and what was expected instead.
+fn unexpected(rest: &str, kind: ErrorKind) -> String {
+    let token = rest
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().next())
+        .map_or_else(|| "end of line".into(), |token| format!("`{token}`"));
+
+    let expected = match &kind {
+        ErrorKind::Digit | ErrorKind::OneOf => "a number",
+        ErrorKind::MapRes => "a number in range",
+        ErrorKind::Eof => "the end of the line",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a line break",
+        ErrorKind::Char | ErrorKind::Tag => "a separator",
+        ErrorKind::AlphaNumeric => "a word",
+        kind => kind.description(),
+    };
+
+    format!("unexpected {token}, expected {expected}")
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::{
+        character::complete::{char, space1},
+        sequence::separated_pair,
+    };
+
+    use super::{
+        blank_line, grid, integer, integers, key_values, lines_of, numbers, parse_all, parse_lines,
+        sections, word,
+    };
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(integer::<i32>("-42 rest"), Ok((" rest", -42)));
+        assert!(integer::<u32>("-42").is_err());
+        assert_eq!(integers::<u8>("7 6  4"), Ok(("", vec![7, 6, 4])));
+        assert_eq!(integers::<i64>("75,47, -61"), Ok(("", vec![75, 47, -61])));
+    }
+
+    #[test]
+    fn parses_lines() {
+        let input = "3   4\n4   3\n";
+        let pair = separated_pair(integer::<u32>, space1, integer::<u32>);
+        assert_eq!(parse_lines(input, pair), Ok(vec![(3, 4), (4, 3)]));
+    }
+
+    #[test]
+    fn parses_key_values() {
+        let input = "190: 10 19\n3267: 81 40 27";
+        assert_eq!(
+            parse_lines(input, key_values(integer::<u64>, integers::<u64>)),
+            Ok(vec![(190, vec![10, 19]), (3267, vec![81, 40, 27])])
+        );
+        assert_eq!(
+            parse_all("abc: 1,2", key_values(word, integers::<u8>)),
+            Ok(("abc", vec![1, 2]))
+        );
+    }
+
+    #[test]
+    fn parses_sections() {
+        let input = "47|53\n97|13\n\n75,47,61\n97,61\n";
+        let rules = lines_of(separated_pair(integer::<u8>, char('|'), integer));
+        let updates = lines_of(integers::<u8>);
+        assert_eq!(
+            parse_all(input, separated_pair(rules, blank_line, updates)),
+            Ok((
+                vec![(47, 53), (97, 13)],
+                vec![vec![75, 47, 61], vec![97, 61]]
+            ))
+        );
+
+        assert_eq!(
+            parse_all("1\n2\n\n3\n", sections(lines_of(integer::<u8>))),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn extracts_numbers() {
+        assert_eq!(numbers::<i64>("p=3,-4 v=-10,7"), Ok(vec![3, -4, -10, 7]));
+        assert_eq!(numbers::<u64>("x-3 y12\nz"), Ok(vec![3, 12]));
+        assert_eq!(numbers::<u8>("no numbers"), Ok(vec![]));
+    }
+
+    #[test]
+    fn parses_grids() {
+        let parsed = grid("#.\n.#", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(parsed[(1, 1)], true);
+        assert_eq!(parsed[(1, 0)], false);
+    }
+
+    #[test]
+    fn parses_grids_of_characters() {
+        let parsed = grid("██\n.é", |c| Some(c)).unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (2, 2));
+        assert_eq!(parsed[(0, 0)], '█');
+        assert_eq!(parsed[(1, 1)], 'é');
+
+        let parsed = grid("ab\ncd\n\n", |c| Some(c)).unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (2, 2));
+        assert_eq!(parsed[(1, 1)], 'd');
+        assert!(grid("ab\n\ncd\n", |c| Some(c)).is_err());
+    }
+
+    #[test]
+    fn ignores_trailing_empty_lines() {
+        let pair = || separated_pair(integer::<u32>, space1, integer::<u32>);
+        assert_eq!(
+            parse_lines("3   4\n4   3\n\n", pair()),
+            Ok(vec![(3, 4), (4, 3)])
+        );
+        assert_eq!(parse_lines("3   4\n \r\n", pair()), Ok(vec![(3, 4)]));
+        assert_eq!(
+            parse_lines("3   4\n4   3\n\n", pair()),
+            parse_all("3   4\n4   3\n\n", lines_of(pair()))
+        );
+        assert!(parse_lines("3   4\n\n4   3\n", pair()).is_err());
+    }
+
+    #[test]
+    fn reports_positions() {
+        let pair = separated_pair(integer::<u32>, space1, integer::<u32>);
+        let error = parse_lines("3   4\n4   x\n", pair).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: unexpected `x`, expected a number"
+        );
+
+        let error = parse_lines("1 2 x", integers::<u8>).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+
+        let error = parse_all("1\n2\n3 4", lines_of(integer::<u8>)).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+
+        let error = numbers::<u8>("1\n 300").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: `300` is out of range");
+
+        let error = grid("ab\na", |c| Some(c)).unwrap_err();
+        assert_eq!(error.line, 2);
+        let error = grid("ab\naX", |c| c.is_lowercase().then_some(c)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected `X`");
+        let error = grid("abcd\na█", |c| c.is_alphabetic().then_some(c)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected `█`");
+        let error = grid("é█\nab\nabc", |c| Some(c)).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
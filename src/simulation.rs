@@ -0,0 +1,156 @@
+/// Helpers for puzzles that repeatedly apply a step to a state, e.g. a guard walking a map or rocks settling.
+/// States are remembered in a hash map, so a simulation notices when it returns to an earlier state instead of running forever.
+use std::{collections::HashMap, hash::Hash};
+
+/// How a simulation ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<S> {
+    /// The step function returned `None`, e.g. because the guard left the map. Holds the last state.
+    Finished { state: S, steps: usize },
+    /// The simulation returned to `state`, which it first reached after `start` steps and reaches again every
+    /// `length` steps.
+    Cycle {
+        state: S,
+        start: usize,
+        length: usize,
+    },
+    /// The simulation neither finished nor repeated a state within the step budget. Holds the last state.
+    OutOfSteps { state: S },
+}
+
+impl<S> Outcome<S> {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished { .. })
+    }
+
+    pub fn is_cycle(&self) -> bool {
+        matches!(self, Self::Cycle { .. })
+    }
+}
+
+/// Applies `step` to `initial` until it returns `None` or a state repeats.
+pub fn simulate<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> Option<S>) -> Outcome<S> {
+    simulate_for(initial, usize::MAX, step)
+}
+
+/// Like [`simulate`], but gives up after `max_steps` steps.
+pub fn simulate_for<S: Clone + Eq + Hash>(
+    initial: S,
+    max_steps: usize,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Outcome<S> {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for steps in 0.. {
+        seen.insert(state.clone(), steps);
+        if steps == max_steps {
+            break;
+        }
+
+        state = match step(&state) {
+            None => return Outcome::Finished { state, steps },
+            Some(next) => next,
+        };
+        if let Some(&start) = seen.get(&state) {
+            return Outcome::Cycle {
+                state,
+                start,
+                length: steps + 1 - start,
+            };
+        }
+    }
+    Outcome::OutOfSteps { state }
+}
+
+/// The state after applying `step` to `initial` `n` times.
+/// Skips whole cycles once a state repeats, so `n` can be far larger than the number of distinct states.
+pub fn fast_forward<S: Clone + Eq + Hash>(
+    initial: S,
+    n: usize,
+    mut step: impl FnMut(&S) -> S,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+
+    for steps in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let length = steps - start;
+            return history.swap_remove(start + (n - start) % length);
+        }
+        seen.insert(state.clone(), steps);
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fast_forward, simulate, simulate_for, Outcome};
+
+    #[test]
+    fn finishes() {
+        let outcome = simulate(10, |&n| (n > 0).then(|| n - 3));
+        assert_eq!(
+            outcome,
+            Outcome::Finished {
+                state: -2,
+                steps: 4
+            }
+        );
+        assert!(outcome.is_finished());
+    }
+
+    #[test]
+    fn detects_cycles() {
+        // 3, 0, 1, 2, 5, 6, 7, 0, 1, ...
+        let outcome = simulate(3, |&n| Some((n * n + 1) % 10 % 8));
+        assert_eq!(
+            outcome,
+            Outcome::Cycle {
+                state: 0,
+                start: 1,
+                length: 6
+            }
+        );
+        assert!(outcome.is_cycle());
+
+        let outcome = simulate(0, |&n| Some(n));
+        assert_eq!(
+            outcome,
+            Outcome::Cycle {
+                state: 0,
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn stops_after_budget() {
+        assert_eq!(
+            simulate_for(0, 5, |&n| Some(n + 1)),
+            Outcome::OutOfSteps { state: 5 }
+        );
+        assert!(simulate_for(0, 5, |&n| Some((n + 1) % 5)).is_cycle());
+        assert_eq!(
+            simulate_for(0, 0, |&n| Some(n)),
+            Outcome::OutOfSteps { state: 0 }
+        );
+    }
+
+    #[test]
+    fn fast_forwards() {
+        let step = |&n: &u64| (n * n + 1) % 10 % 8;
+        for n in 0..30 {
+            let expected = (0..n).fold(3, |state, _| step(&state));
+            assert_eq!(fast_forward(3, n, step), expected, "after {n} steps");
+        }
+        assert_eq!(fast_forward(3, 1_000_000_000_000, step), 5);
+    }
+}
@@ -0,0 +1,149 @@
+/// Lightweight heap profiling for solutions that run in-process.
+/// The runner installs [`CountingAllocator`] as its global allocator and marks it as installed at startup.
+/// Outside of [`measure`], allocations only pay for one relaxed load of a flag.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+static IS_INSTALLED: AtomicBool = AtomicBool::new(false);
+static IS_COUNTING: AtomicBool = AtomicBool::new(false);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap usage of one execution of a solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Highest amount of heap memory in use at once, relative to the start of the execution.
+    pub peak_bytes: u64,
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+}
+
+/// A global allocator that forwards to [`System`] and counts allocations while measuring.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// Enables [`measure`]. Call once at startup of the binary that declares this as its global allocator.
+    pub fn mark_installed(&self) {
+        IS_INSTALLED.store(true, Ordering::Relaxed);
+    }
+
+    fn record_alloc(size: usize) {
+        if !IS_COUNTING.load(Ordering::Relaxed) {
+            return;
+        }
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        if IS_COUNTING.load(Ordering::Relaxed) {
+            // memory allocated before measuring started may be freed while measuring.
+            let _ = CURRENT_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(current.saturating_sub(size))
+            });
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Runs `func` and measures its heap usage.
+/// Returns [`None`] as usage if [`CountingAllocator`] was not marked as the global allocator,
+/// see [`CountingAllocator::mark_installed`].
+/// Allocations of other threads are counted as well, so measurements should not run concurrently.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryUsage>) {
+    if !IS_INSTALLED.load(Ordering::Relaxed) {
+        return (func(), None);
+    }
+
+    CURRENT_BYTES.store(0, Ordering::Relaxed);
+    PEAK_BYTES.store(0, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    IS_COUNTING.store(true, Ordering::SeqCst);
+
+    let result = func();
+
+    IS_COUNTING.store(false, Ordering::SeqCst);
+
+    let usage = MemoryUsage {
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed) as u64,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) as u64,
+    };
+
+    (result, Some(usage))
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", units[unit])
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn measures_nothing_without_the_allocator() {
+        let (result, usage) = measure(|| vec![1_u8; 1024].len());
+        assert_eq!(result, 1024);
+        assert_eq!(usage, None);
+    }
+}
@@ -0,0 +1,332 @@
+/// Native client for the Advent of Code website.
+/// Fetches inputs and puzzle descriptions and posts answers using the session cookie.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{
+    puzzle_markdown,
+    submission::{self, SubmissionOutcome},
+    Puzzle,
+};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/ingwinlu/aoc2024 by ingwinlu";
+static SESSION_FILE_NAME: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16),
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set ADVENT_OF_CODE_SESSION or create ~/{SESSION_FILE_NAME}."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "could not write file: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(t) => AocClientError::Transport(t.to_string()),
+        }
+    }
+}
+
+/// HTTP client bound to a session.
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Configures a client from the environment.
+    /// `AOC_BASE_URL` can be set to point the client at a stand-in server.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the personal input for a puzzle.
+    pub fn get_input(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.puzzle_url(puzzle)))
+    }
+
+    /// Fetches the page of a puzzle and converts its description to markdown.
+    pub fn get_puzzle(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        let html = self.get(&self.puzzle_url(puzzle))?;
+        Ok(puzzle_markdown::from_html(&html))
+    }
+
+    /// Fetches the page of a puzzle and reads the answers of its solved parts.
+    pub fn get_solved_answers(&self, puzzle: Puzzle) -> Result<Vec<String>, AocClientError> {
+        let html = self.get(&self.puzzle_url(puzzle))?;
+        Ok(submission::solved_answers(&html))
+    }
+
+    /// Posts an answer for one part of a puzzle and returns the response page.
+    pub fn post_answer(
+        &self,
+        puzzle: Puzzle,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.puzzle_url(puzzle)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        Ok(response.into_string()?)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call()?;
+        Ok(response.into_string()?)
+    }
+
+    fn puzzle_url(&self, puzzle: Puzzle) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+pub fn check() -> Result<(), AocClientError> {
+    AocClient::from_env().map(|_| ())
+}
+
+pub fn read(puzzle: Puzzle) -> Result<String, AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.get_puzzle(puzzle)?;
+    write_data_file(&puzzle.puzzle_path(), &description)?;
+    println!("{description}");
+    Ok(description)
+}
+
+pub fn download(puzzle: Puzzle) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = puzzle.input_path();
+    let puzzle_path = puzzle.puzzle_path();
+
+    write_data_file(&input_path, &client.get_input(puzzle)?)?;
+    write_data_file(&puzzle_path, &client.get_puzzle(puzzle)?)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(puzzle: Puzzle, part: u8, result: &str) -> Result<SubmissionOutcome, AocClientError> {
+    let client = AocClient::from_env()?;
+    let page = client.post_answer(puzzle, part, result)?;
+    Ok(SubmissionOutcome::from_response(
+        &puzzle_markdown::from_html(&page),
+    ))
+}
+
+/// Returns the accepted answer of a part that is already solved, if any.
+pub fn solved_answer(puzzle: Puzzle, part: u8) -> Result<Option<String>, AocClientError> {
+    let client = AocClient::from_env()?;
+    let answers = client.get_solved_answers(puzzle)?;
+    Ok(answers.into_iter().nth(usize::from(part) - 1))
+}
+
+/// Writes a file to the data directory of a year, which may not exist yet.
+fn write_data_file(path: &str, contents: &str) -> Result<(), io::Error> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Reads the session cookie from `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`.
+fn get_session() -> Option<String> {
+    let session = match env::var("ADVENT_OF_CODE_SESSION") {
+        Ok(x) => x,
+        Err(_) => {
+            let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+            fs::read_to_string(PathBuf::from(home).join(SESSION_FILE_NAME)).ok()?
+        }
+    };
+
+    let session = session.trim();
+    if session.is_empty() {
+        None
+    } else {
+        Some(session.to_string())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::AocClient;
+    use crate::{
+        day,
+        template::{puzzle_markdown, submission::SubmissionOutcome, Puzzle},
+    };
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// A stand-in for adventofcode.com that answers a single request with `body`.
+    /// Returns the server's base URL and a handle yielding the raw request it received.
+    fn serve_once(status: u16, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (url, server) = serve_once(200, "1 2\n3 4\n");
+        let client = AocClient::new(&url, "abc");
+        assert_eq!(
+            client.get_input(Puzzle::new(2024, day!(1))).unwrap(),
+            "1 2\n3 4\n"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (url, server) = serve_once(
+            200,
+            "<html><main><article class=\"day-desc\"><h2>--- Day 7: Test ---</h2><p>Hello <em>world</em>.</p></article></main></html>",
+        );
+        let client = AocClient::new(&url, "abc");
+        let puzzle = client.get_puzzle(Puzzle::new(2024, day!(7))).unwrap();
+        assert_eq!(puzzle, "## --- Day 7: Test ---\n\nHello *world*.\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/7 HTTP/1.1"));
+    }
+
+    #[test]
+    fn fetches_solved_answers() {
+        let (url, server) = serve_once(
+            200,
+            "<main><article><p>Part one.</p></article><p>Your puzzle answer was <code>161</code>.</p></main>",
+        );
+        let client = AocClient::new(&url, "abc");
+        let answers = client
+            .get_solved_answers(Puzzle::new(2024, day!(3)))
+            .unwrap();
+        assert_eq!(answers, vec!["161"]);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/3 HTTP/1.1"));
+    }
+
+    #[test]
+    fn posts_answer() {
+        let (url, server) = serve_once(200, "<article><p>That's the right answer!</p></article>");
+        let client = AocClient::new(&url, "abc");
+        let page = client
+            .post_answer(Puzzle::new(2024, day!(3)), 2, "48")
+            .unwrap();
+        assert_eq!(
+            SubmissionOutcome::from_response(&puzzle_markdown::from_html(&page)),
+            SubmissionOutcome::Correct
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/3/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=48"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, server) = serve_once(404, "not yet");
+        let client = AocClient::new(&url, "abc");
+        let err = client.get_input(Puzzle::new(2024, day!(25))).unwrap_err();
+        assert!(matches!(err, super::AocClientError::BadStatus(404)));
+        server.join().unwrap();
+    }
+}
@@ -1,5 +1,24 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    registry::Solution,
+    run_multi::run_multi,
+    runner::{Limits, RunOptions},
+};
+
+pub fn handle(year: u16, is_release: bool, jobs: usize, limits: Limits, solutions: &[Solution]) {
+    let run = run_multi(
+        year,
+        &all_days(year).collect(),
+        is_release,
+        RunOptions::default(),
+        jobs,
+        limits,
+        solutions,
+    );
+
+    if run.has_failures {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,67 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{examples, Puzzle};
+
+fn has_content(path: &str) -> bool {
+    fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty())
+}
+
+/// Writes `content` to `path` unless the file already has content.
+fn write_example(path: &str, content: &str, overwrite: bool) {
+    if has_content(path) && !overwrite {
+        println!("Skipped \"{path}\", it is not empty. Pass `--overwrite` to replace it.");
+        return;
+    }
+
+    if let Some(dir) = Path::new(path).parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    match fs::write(path, content) {
+        Ok(()) => println!("Wrote example to \"{path}\""),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+pub fn handle(puzzle: Puzzle, overwrite: bool) {
+    let Ok(markdown) = fs::read_to_string(puzzle.puzzle_path()) else {
+        eprintln!("Puzzle description not found, run `cargo download {puzzle}` first.");
+        process::exit(1);
+    };
+
+    let examples = examples::extract(&markdown);
+
+    for example in &examples {
+        let Some(input) = &example.input else {
+            continue;
+        };
+        let path = match example.part {
+            1 => puzzle.example_path(),
+            part => puzzle.example_part_path(part),
+        };
+        write_example(&path, input, overwrite);
+    }
+
+    let manifest_path = puzzle.example_manifest_path();
+    if has_content(&manifest_path) && !overwrite {
+        println!("Skipped \"{manifest_path}\", it is not empty. Pass `--overwrite` to replace it.");
+        return;
+    }
+
+    let manifest = examples::to_manifest(puzzle.day, &examples);
+    if let Err(e) = manifest.store_file(puzzle) {
+        eprintln!("Failed to write example manifest: {e}");
+        process::exit(1);
+    }
+
+    println!("Wrote expected answers to \"{manifest_path}\"");
+    for example in &examples {
+        match &example.answer {
+            Some(answer) => println!("Part {}: `{answer}`", example.part),
+            None => println!("Part {}: no expected answer found", example.part),
+        }
+    }
+}
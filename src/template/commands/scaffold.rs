@@ -1,10 +1,11 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::Puzzle;
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -20,6 +21,11 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    // the data directories of a year are created along with its first day.
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +33,10 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: Puzzle, overwrite: bool) {
+    let input_path = puzzle.input_path();
+    let example_path = puzzle.example_path();
+    let module_path = format!("src/bin/{puzzle}.rs");
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -42,7 +48,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match file.write_all(
         MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace("%DAY_NUMBER%", &puzzle.day.into_inner().to_string())
             .as_bytes(),
     ) {
         Ok(()) => {
@@ -75,5 +81,5 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {puzzle}` to run your solution.");
 }
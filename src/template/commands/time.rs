@@ -1,19 +1,65 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
+use crate::template::registry::Solution;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::runner::{Limits, RunOptions, Statistic};
+use crate::template::timing_history::{self, RunContext};
+use crate::template::timings::{PartDelta, Timings};
+use crate::template::{all_days, readme_benchmarks, Day, Puzzle, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Prints deltas of the new timings versus the stored ones. Returns whether any part regressed.
+fn print_comparison(deltas: &[PartDelta], threshold: f64) -> bool {
+    println!("\n{ANSI_BOLD}Compared with stored timings (threshold {threshold}%){ANSI_RESET}");
+    println!("| Day | Part | Stored | Current | Change |");
+
+    let format_nanos = |nanos: u64| format!("{:.1?}", Duration::from_nanos(nanos));
+
+    for delta in deltas {
+        let marker = if delta.is_regression(threshold) {
+            " ✖ regression"
+        } else if !delta.is_comparable {
+            " ? different statistic"
+        } else {
+            ""
+        };
+
+        println!(
+            "| {} | {} | {} | {} | {:+.1}%{marker} |",
+            delta.day,
+            delta.part,
+            format_nanos(delta.baseline_nanos),
+            format_nanos(delta.current_nanos),
+            delta.change_percent()
+        );
+    }
+
+    deltas.iter().any(|d| d.is_regression(threshold))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: u16,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    statistic: Statistic,
+    measure_memory: bool,
+    limits: Limits,
+    compare: Option<f64>,
+    solutions: &[Solution],
+) {
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            // comparisons need the days that have stored timings.
+            if run_all || compare.is_some() {
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,20 +67,96 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run = run_multi(
+        year,
+        &days_to_run,
+        true,
+        RunOptions {
+            is_timed: true,
+            statistic,
+            is_quiet: false,
+            measure_memory,
+        },
+        1,
+        limits,
+        solutions,
+    );
+    let timings = run.timings.unwrap();
+
+    if let Some(threshold) = compare {
+        if print_comparison(&timings.compare(&stored_timings), threshold) {
+            eprintln!("\nSome parts got slower than the threshold allows, not storing timings.");
+            process::exit(1);
+        }
+    }
 
     if store {
+        // the context is captured first, storing timings must not mark the tree as dirty.
+        let context = RunContext::current();
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
+
+        if timing_history::append(year, &timings, &context).is_err() {
+            eprintln!("Failed to append timings to the history.");
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
+
+    if run.has_failures {
+        process::exit(1);
+    }
+}
+
+/// Prints how the timings of a puzzle evolved across stored runs.
+pub fn handle_history(puzzle: Puzzle) {
+    let entries = match timing_history::read_puzzle(puzzle) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read timing history: {e}");
+            process::exit(1);
+        }
+    };
+
+    if entries.is_empty() {
+        println!("No stored timings for {puzzle}, run `cargo time {puzzle} --store` first.");
+        return;
+    }
+
+    let format_nanos = |nanos: Option<u64>| {
+        nanos.map_or("-".into(), |n| format!("{:.1?}", Duration::from_nanos(n)))
+    };
+
+    println!("{ANSI_BOLD}{} Day {}{ANSI_RESET}", puzzle.year, puzzle.day);
+    println!("| Date | Commit | rustc | Statistic | Part 1 | Part 2 | Total | Change |");
+
+    let mut previous: Option<u64> = None;
+
+    for entry in &entries {
+        let total = entry.total_nanos();
+        #[allow(clippy::cast_precision_loss)]
+        let change = previous.filter(|&p| p > 0).map_or("-".into(), |p| {
+            format!("{:+.1}%", (total as f64 - p as f64) / p as f64 * 100.0)
+        });
+        previous = Some(total);
+
+        println!(
+            "| {} | {} | {} | {} | {} | {} | {} | {change} |",
+            timing_history::format_timestamp(entry.context.timestamp),
+            entry.context.commit.as_deref().unwrap_or("-"),
+            entry.context.rustc.as_deref().unwrap_or("-"),
+            entry.statistic,
+            format_nanos(entry.part_1_nanos),
+            format_nanos(entry.part_2_nanos),
+            format_nanos(Some(total)),
+        );
+    }
 }
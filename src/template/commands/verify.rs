@@ -0,0 +1,126 @@
+use std::{fs, process};
+
+use crate::template::ledger::Ledger;
+use crate::template::registry::{self, Solution};
+use crate::template::run_multi::{child_commands, Error};
+use crate::template::runner::{Limits, RunOptions};
+use crate::template::{all_days, Day, Puzzle, ANSI_BOLD, ANSI_RESET};
+
+enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Unknown,
+}
+
+impl Verdict {
+    fn new(expected: Option<String>, actual: Option<String>, error: Option<String>) -> Self {
+        match (expected, actual) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected,
+                actual: error
+                    .map(|e| format!("✖ ({e})"))
+                    .or(actual)
+                    .unwrap_or_else(|| "✖".into()),
+            },
+        }
+    }
+
+    fn cell(&self) -> String {
+        match self {
+            Verdict::Pass => "✔".into(),
+            Verdict::Fail { .. } => "✖".into(),
+            Verdict::Unknown => "?".into(),
+        }
+    }
+}
+
+/// Reads known answers from `data/<year>/answers/DD.txt` (part 1 on the first, part 2 on the second line),
+/// falling back to the correct answers recorded in the submission ledger.
+fn expected_answers(puzzle: Puzzle, ledger: &Ledger) -> [Option<String>; 2] {
+    let file = fs::read_to_string(puzzle.data_path("answers", "txt")).unwrap_or_default();
+    let mut lines = file.lines().map(str::trim);
+
+    [1, 2].map(|part| {
+        lines
+            .next()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .or_else(|| ledger.correct_answer(puzzle.day, part).map(String::from))
+    })
+}
+
+pub fn handle(year: u16, day: Option<Day>, is_release: bool, solutions: &[Solution]) {
+    let ledger = match Ledger::read_from_file(year) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Failed to read submission ledger: {e}");
+            process::exit(1);
+        }
+    };
+    let mut failures = vec![];
+
+    println!("{ANSI_BOLD}| Day | Part 1 | Part 2 |{ANSI_RESET}");
+
+    for day in all_days(year).filter(|d| day.is_none_or(|day| day == *d)) {
+        let puzzle = Puzzle::new(year, day);
+        if !std::path::Path::new(&puzzle.bin_path()).exists() {
+            continue;
+        }
+
+        let result = match registry::find(solutions, puzzle) {
+            Some(solution) => {
+                let options = RunOptions {
+                    is_quiet: true,
+                    ..RunOptions::default()
+                };
+                solution.run_with_input(&options).map_err(Error::Solution)
+            }
+            None => child_commands::capture_solution(puzzle, is_release, Limits::default()).1,
+        };
+
+        // a day that crashed fails even if none of its answers are known.
+        let reports = result.unwrap_or_else(|e| {
+            failures.push(format!("Day {day}: {e}"));
+            vec![]
+        });
+
+        let actual = [1, 2].map(|part| child_commands::get_answer(&reports, part));
+        let errors = [1, 2].map(|part| child_commands::get_error(&reports, part));
+        let expected = expected_answers(puzzle, &ledger);
+
+        let verdicts: Vec<Verdict> = expected
+            .into_iter()
+            .zip(actual)
+            .zip(errors)
+            .map(|((expected, actual), error)| Verdict::new(expected, actual, error))
+            .collect();
+
+        println!(
+            "| {day}  |   {}    |   {}    |",
+            verdicts[0].cell(),
+            verdicts[1].cell()
+        );
+
+        for (part, verdict) in verdicts.into_iter().enumerate() {
+            if let Verdict::Fail { expected, actual } = verdict {
+                failures.push(format!(
+                    "Day {day} part {}: expected {expected}, got {actual}",
+                    part + 1
+                ));
+            }
+        }
+    }
+
+    println!();
+
+    if failures.is_empty() {
+        println!("All known answers verified.");
+    } else {
+        for failure in &failures {
+            eprintln!("{failure}");
+        }
+        process::exit(1);
+    }
+}
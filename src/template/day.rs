@@ -1,3 +1,4 @@
+use std::env;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -8,7 +9,34 @@ use chrono::{Datelike, FixedOffset, Utc};
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// The highest day number of any event, the single upper bound of a [`Day`].
+pub const MAX_DAY: u8 = crate::max_day!();
+
+/// The first event that only has 12 puzzles.
+const FIRST_SHORT_EVENT: u16 = 2025;
+
+/// Number of puzzles of an event, e.g. `25` for 2024.
+///
+/// Can be configured per year with an `AOC_DAYS_<year>` variable, e.g. `AOC_DAYS_2025 = "12"`.
+/// Without it, events before 2025 have [`MAX_DAY`] puzzles and later ones have 12.
+pub fn days_in_year(year: u16) -> u8 {
+    configured_days_in_year(year, |name| env::var(name).ok())
+}
+
+/// Number of puzzles of an event, looking up the `AOC_DAYS_<year>` variable with `var`.
+fn configured_days_in_year(year: u16, var: impl Fn(&str) -> Option<String>) -> u8 {
+    var(&format!("AOC_DAYS_{year}"))
+        .and_then(|days| days.parse().ok())
+        .filter(|days| (1..=MAX_DAY).contains(days))
+        .unwrap_or(if year < FIRST_SHORT_EVENT {
+            MAX_DAY
+        } else {
+            12
+        })
+}
+
+/// A valid day number of advent (i.e. an integer in range 1 to [`MAX_DAY`]).
+/// Not every event has [`MAX_DAY`] days, see [`days_in_year`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,7 +53,7 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
@@ -45,11 +73,12 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's one of the days of this year's event in december, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        let days = days_in_year(u16::try_from(today.year()).ok()?);
+        if today.month() == 12 && today.day() <= u32::from(days) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -94,26 +123,29 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {MAX_DAY}")
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of the event in `year`, see [`days_in_year`].
+pub fn all_days(year: u16) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of an event from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: u16) -> Self {
+        Self {
+            current: 1,
+            last: days_in_year(year),
+        }
     }
 }
 
@@ -121,10 +153,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and `last` is never above `MAX_DAY`.
         let day = Day(self.current);
         self.current += 1;
 
@@ -139,11 +171,12 @@ impl Iterator for AllDays {
 macro_rules! day {
     ($day:expr) => {{
         const _ASSERT: () = assert!(
-            $day != 0 && $day <= 25,
+            $day != 0 && $day <= $crate::template::MAX_DAY,
             concat!(
                 "invalid day number `",
                 $day,
-                "`, expecting a value between 1 and 25"
+                "`, expecting a value between 1 and ",
+                $crate::max_day!()
             ),
         );
         $crate::template::Day::__new_unchecked($day)
@@ -154,11 +187,11 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, configured_days_in_year, Day, MAX_DAY};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(2024);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +220,39 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_of_short_events() {
+        assert_eq!(all_days(2025).last(), Some(Day(12)));
+        assert_eq!(all_days(2025).count(), 12);
+    }
+
+    #[test]
+    fn days_in_year_is_configurable() {
+        let unset = |_: &str| None;
+        assert_eq!(configured_days_in_year(2015, unset), 25);
+        assert_eq!(configured_days_in_year(2030, unset), 12);
+
+        let var = |name: &str| match name {
+            "AOC_DAYS_2031" => Some("10".to_string()),
+            "AOC_DAYS_2032" => Some("30".to_string()),
+            _ => None,
+        };
+        assert_eq!(configured_days_in_year(2031, var), 10);
+        assert_eq!(configured_days_in_year(2032, var), 12);
+        assert_eq!(configured_days_in_year(2015, var), 25);
+    }
+
+    #[test]
+    fn rejects_days_above_max_day() {
+        assert_eq!(Day::new(MAX_DAY), Some(Day(MAX_DAY)));
+        assert_eq!(Day::new(MAX_DAY + 1), None);
+        let err = (MAX_DAY + 1).to_string().parse::<Day>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("expecting a day number between 1 and {MAX_DAY}")
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,257 @@
+/// Declarative example tests. Every day can list its example inputs and their expected answers in
+/// `data/<year>/examples/<day>.json`, the `solution!` macro turns the manifest into one test per part.
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{runner::PartResult, year_data_dir, Puzzle};
+
+/// An example input and the expected answer of every part it applies to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleCase {
+    /// Name of the input file in the examples folder, e.g. `03-2.txt`.
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl ExampleCase {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            _ => self.part_2.as_deref(),
+        }
+    }
+}
+
+/// The example cases of a day. Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub examples: Vec<ExampleCase>,
+}
+
+impl Manifest {
+    /// Dehydrate the manifest of a puzzle to a JSON file.
+    pub fn store_file(&self, puzzle: Puzzle) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        fs::create_dir_all(format!("{}/examples", year_data_dir(puzzle.year)))?;
+        let mut file = fs::File::create(puzzle.example_manifest_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the manifest of a puzzle from a JSON file. If not present, returns [`None`].
+    pub fn read_from_file(puzzle: Puzzle) -> Result<Option<Self>, String> {
+        let Ok(content) = fs::read_to_string(puzzle.example_manifest_path()) else {
+            return Ok(None);
+        };
+        Manifest::try_from(content).map(Some)
+    }
+}
+
+/// Runs `func` on every example of the manifest that has an expected answer for `part`.
+///
+/// # Panics
+/// If the manifest is missing or malformed, if no example has an expected answer for `part`,
+/// or if any example does not produce its expected answer.
+pub fn check_examples<R: PartResult>(func: impl Fn(&str) -> R, puzzle: Puzzle, part: u8) {
+    let manifest = match Manifest::read_from_file(puzzle) {
+        Ok(Some(manifest)) => manifest,
+        Ok(None) => panic!(
+            "no example manifest for {puzzle}, expected \"{}\"",
+            puzzle.example_manifest_path()
+        ),
+        Err(e) => panic!("failed to read example manifest of {puzzle}: {e}"),
+    };
+
+    let cases: Vec<_> = manifest
+        .examples
+        .iter()
+        .filter_map(|case| Some((case, case.expected(part)?)))
+        .collect();
+
+    assert!(
+        !cases.is_empty(),
+        "no example of {puzzle} has an expected answer for part {part}, add one to \"{}\"",
+        puzzle.example_manifest_path()
+    );
+
+    let failures: Vec<String> = cases
+        .into_iter()
+        .filter_map(|(case, expected)| {
+            let path = format!("{}/examples/{}", year_data_dir(puzzle.year), case.input);
+            let Ok(input) = fs::read_to_string(&path) else {
+                return Some(format!("{}: could not open \"{path}\"", case.input));
+            };
+
+            match func(&input).answer() {
+                Ok(Some(answer)) if answer == expected => None,
+                Ok(answer) => Some(format!(
+                    "{}: expected `{expected}`, got `{}`",
+                    case.input,
+                    answer.as_deref().unwrap_or("None")
+                )),
+                Err(e) => Some(format!(
+                    "{}: expected `{expected}`, failed: {e}",
+                    case.input
+                )),
+            }
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} example(s) of part {part} failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Integer answers are written as JSON numbers as long as they can be represented exactly.
+fn answer_to_json(answer: Option<&String>) -> JsonValue {
+    const MAX_EXACT: u64 = 1 << 53;
+
+    match answer {
+        #[allow(clippy::cast_precision_loss)]
+        Some(answer) => match answer.parse::<u64>() {
+            Ok(n) if n <= MAX_EXACT => JsonValue::Number(n as f64),
+            _ => JsonValue::String(answer.clone()),
+        },
+        None => JsonValue::Null,
+    }
+}
+
+impl From<&Manifest> for JsonValue {
+    fn from(value: &Manifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "examples".into(),
+            JsonValue::Array(value.examples.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Manifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_examples = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("examples")
+            .ok_or("expected JSON document to have key `examples`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.examples` to be an array.")?;
+
+        Ok(Manifest {
+            examples: json_examples
+                .iter()
+                .map(ExampleCase::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&ExampleCase> for JsonValue {
+    fn from(value: &ExampleCase) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("input".into(), JsonValue::String(value.input.clone()));
+        map.insert("part_1".into(), answer_to_json(value.part_1.as_ref()));
+        map.insert("part_2".into(), answer_to_json(value.part_2.as_ref()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for ExampleCase {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example to be a JSON object.")?;
+
+        let answer = |key: &str| -> Result<Option<String>, String> {
+            match json.get(key) {
+                Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+                Some(JsonValue::Number(n)) => Ok(Some(n.to_string())),
+                Some(JsonValue::Null) | None => Ok(None),
+                Some(_) => Err(format!(
+                    "Expected example.{key} to be null, string or number."
+                )),
+            }
+        };
+
+        Ok(ExampleCase {
+            input: json
+                .get("input")
+                .and_then(|v| v.get::<String>())
+                .ok_or("Expected example.input to be a string.")?
+                .clone(),
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExampleCase, Manifest};
+    use tinyjson::JsonValue;
+
+    fn get_mock_manifest() -> Manifest {
+        Manifest {
+            examples: vec![
+                ExampleCase {
+                    input: "03.txt".into(),
+                    part_1: Some("161".into()),
+                    part_2: None,
+                },
+                ExampleCase {
+                    input: "03-2.txt".into(),
+                    part_1: None,
+                    part_2: Some("abc-def".into()),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn reads_hand_written_manifests() {
+        let manifest = Manifest::try_from(
+            r#"{
+                "examples": [
+                    { "input": "03.txt", "part_1": 161 },
+                    { "input": "03-2.txt", "part_2": "abc-def" }
+                ]
+            }"#
+            .to_string(),
+        )
+        .unwrap();
+        assert_eq!(manifest, get_mock_manifest());
+        assert_eq!(manifest.examples[0].expected(1), Some("161"));
+        assert_eq!(manifest.examples[0].expected(2), None);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let manifest = get_mock_manifest();
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert!(json.contains(r#""part_1":161"#));
+        assert_eq!(Manifest::try_from(json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn rejects_malformed_manifests() {
+        assert!(Manifest::try_from(r#"{ "examples": [{ "part_1": 1 }] }"#.to_string()).is_err());
+        assert!(Manifest::try_from(r#"{ "cases": [] }"#.to_string()).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+/// Extracts example inputs and their expected answers from downloaded puzzle markdown.
+/// Relies on the shape of the markdown written by `puzzle_markdown`: examples are fenced code blocks
+/// and answers are emphasized inline code like *`11`*.
+use std::mem;
+
+use crate::template::{
+    example_manifest::{ExampleCase, Manifest},
+    Day,
+};
+
+/// Heading that starts the second part of a puzzle description.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// The example of one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartExample {
+    pub part: u8,
+    /// The example input, [`None`] if the part reuses the example of part one.
+    pub input: Option<String>,
+    /// The expected answer for the example.
+    pub answer: Option<String>,
+}
+
+/// Extracts the example of every part described in `markdown`.
+pub fn extract(markdown: &str) -> Vec<PartExample> {
+    let (part_one, part_two) = match markdown.split_once(PART_TWO_HEADING) {
+        Some((part_one, part_two)) => (part_one, Some(part_two)),
+        None => (markdown, None),
+    };
+
+    let mut examples = vec![extract_part(1, part_one)];
+
+    if let Some(part_two) = part_two {
+        let mut example = extract_part(2, part_two);
+        if example.input == examples[0].input {
+            example.input = None;
+        }
+        examples.push(example);
+    }
+
+    examples
+}
+
+fn extract_part(part: u8, section: &str) -> PartExample {
+    let mut blocks: Vec<(String, String)> = vec![];
+    let mut text = String::new();
+    let mut block: Option<String> = None;
+
+    for line in section.lines() {
+        match (&mut block, line == "```") {
+            (None, true) => block = Some(String::new()),
+            (Some(_), true) => {
+                let content = block.take().unwrap_or_default();
+                blocks.push((mem::take(&mut text), content));
+            }
+            (Some(content), false) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            (None, false) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+
+    // puzzles introduce their example with e.g. "For example:", later blocks often show intermediate steps.
+    let input = blocks
+        .iter()
+        .find(|(text, _)| text.to_lowercase().contains("example"))
+        .or(blocks.first())
+        .map(|(_, content)| content.clone());
+
+    // the answer to the example is the last emphasized value of a part.
+    let prose: String = blocks
+        .iter()
+        .map(|(text, _)| text.as_str())
+        .chain([text.as_str()])
+        .collect();
+    let answer = prose
+        .rfind("*`")
+        .and_then(|start| prose[start + 2..].split_once("`*"))
+        .map(|(answer, _)| answer.to_string());
+
+    PartExample {
+        part,
+        input,
+        answer,
+    }
+}
+
+/// Lists the extracted examples of a day as test cases. Parts without an own example use the one of part one.
+pub fn to_manifest(day: Day, examples: &[PartExample]) -> Manifest {
+    let mut cases = vec![ExampleCase {
+        input: format!("{day}.txt"),
+        part_1: None,
+        part_2: None,
+    }];
+
+    for example in examples {
+        match (example.part, &example.input) {
+            (1, _) => cases[0].part_1.clone_from(&example.answer),
+            (part, Some(_)) => cases.push(ExampleCase {
+                input: format!("{day}-{part}.txt"),
+                part_1: None,
+                part_2: example.answer.clone(),
+            }),
+            (_, None) => cases[0].part_2.clone_from(&example.answer),
+        }
+    }
+
+    Manifest { examples: cases }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, to_manifest, PartExample};
+    use crate::day;
+
+    const MARKDOWN: &str = "## --- Day 1: Historian Hysteria ---
+
+The map looks like this:
+
+```
+..#
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+Walking through the pairs:
+
+```
+3 -> 4
+```
+
+In the example above, this is *`2`*.
+
+## --- Part Two ---
+
+Now consider *`similarity`*. For the example above, the score is *`31`*.
+";
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        assert_eq!(
+            extract(MARKDOWN),
+            vec![
+                PartExample {
+                    part: 1,
+                    input: Some("3   4\n4   3\n".into()),
+                    answer: Some("2".into()),
+                },
+                PartExample {
+                    part: 2,
+                    input: None,
+                    answer: Some("31".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_separate_example_of_part_two() {
+        let markdown = "For example:\n\n```\na\n```\n\nIt is *`1`*.\n\n## --- Part Two ---\n\nHere is a new example:\n\n```\nb\n```\n\nIt is *`2`*.\n";
+        let examples = extract(markdown);
+        assert_eq!(examples[1].input.as_deref(), Some("b\n"));
+        assert_eq!(examples[1].answer.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn extracts_unsolved_puzzles() {
+        let examples = extract("No answer here.\n\n```\na\n```\n");
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].input.as_deref(), Some("a\n"));
+        assert_eq!(examples[0].answer, None);
+    }
+
+    #[test]
+    fn lists_examples_in_manifest() {
+        let manifest = to_manifest(day!(3), &extract(MARKDOWN));
+        assert_eq!(manifest.examples.len(), 1);
+        assert_eq!(manifest.examples[0].input, "03.txt");
+        assert_eq!(manifest.examples[0].expected(1), Some("2"));
+        assert_eq!(manifest.examples[0].expected(2), Some("31"));
+
+        let separate = [
+            PartExample {
+                part: 1,
+                input: Some("a\n".into()),
+                answer: Some("1".into()),
+            },
+            PartExample {
+                part: 2,
+                input: Some("b\n".into()),
+                answer: Some("2".into()),
+            },
+        ];
+        let manifest = to_manifest(day!(3), &separate);
+        assert_eq!(manifest.examples[0].expected(2), None);
+        assert_eq!(manifest.examples[1].input, "03-2.txt");
+        assert_eq!(manifest.examples[1].expected(2), Some("2"));
+    }
+}
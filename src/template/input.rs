@@ -0,0 +1,167 @@
+/// Selects the input a solution binary runs against. `cargo solve` forwards its input flags to the binary.
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::template::Puzzle;
+
+/// Where a solution binary reads its input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/<year>/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/<year>/examples`. Part two uses its own example if there is one.
+    Example,
+    /// A file at a path relative to the working directory, e.g. a generated stress input.
+    File(String),
+    /// Everything written to standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the source from the arguments passed to a solution binary.
+    pub fn from_args() -> Self {
+        Self::parse(&env::args().collect::<Vec<_>>())
+    }
+
+    fn parse(args: &[String]) -> Self {
+        if let Some(index) = args.iter().position(|x| x == "--input") {
+            if let Some(path) = args.get(index + 1) {
+                return InputSource::File(path.clone());
+            }
+        }
+
+        if args.iter().any(|x| x == "--stdin") {
+            InputSource::Stdin
+        } else if args.iter().any(|x| x == "--example") {
+            InputSource::Example
+        } else {
+            InputSource::Puzzle
+        }
+    }
+
+    /// The arguments that select this source, the inverse of [`InputSource::from_args`].
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example => vec!["--example".into()],
+            InputSource::File(path) => vec!["--input".into(), path.clone()],
+            InputSource::Stdin => vec!["--stdin".into()],
+        }
+    }
+
+    /// Reads the input of a puzzle from this source.
+    pub fn read(&self, puzzle: Puzzle) -> Result<Inputs, String> {
+        let input = match self {
+            InputSource::Puzzle => read_path(&puzzle.input_path())?,
+            InputSource::Example => read_path(&puzzle.example_path())?,
+            InputSource::File(path) => read_path(path)?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read input from stdin: {e}"))?;
+                input
+            }
+        };
+
+        let part_two = match self {
+            InputSource::Example if Path::new(&puzzle.example_part_path(2)).exists() => {
+                Some(read_path(&puzzle.example_part_path(2))?)
+            }
+            _ => None,
+        };
+
+        Ok(Inputs { input, part_two })
+    }
+}
+
+fn read_path(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not open input file \"{path}\": {e}"))
+}
+
+/// The input of every part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inputs {
+    input: String,
+    part_two: Option<String>,
+}
+
+impl Inputs {
+    /// The input of `part`.
+    pub fn get(&self, part: u8) -> &str {
+        match (part, &self.part_two) {
+            (2, Some(input)) => input,
+            _ => &self.input,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+    use crate::{day, template::Puzzle};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_sources() {
+        assert_eq!(InputSource::parse(&args(&["2024-01"])), InputSource::Puzzle);
+        assert_eq!(
+            InputSource::parse(&args(&["2024-01", "--example"])),
+            InputSource::Example
+        );
+        assert_eq!(
+            InputSource::parse(&args(&["2024-01", "--stdin", "--time"])),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::parse(&args(&["2024-01", "--input", "stress.txt"])),
+            InputSource::File("stress.txt".into())
+        );
+    }
+
+    #[test]
+    fn roundtrips_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example,
+            InputSource::File("stress.txt".into()),
+            InputSource::Stdin,
+        ] {
+            let mut args = args(&["2024-01"]);
+            args.extend(source.to_args());
+            assert_eq!(InputSource::parse(&args), source);
+        }
+    }
+
+    #[test]
+    fn reads_examples_of_parts() {
+        let inputs = InputSource::Example
+            .read(Puzzle::new(2024, day!(3)))
+            .unwrap();
+        assert!(inputs.get(1).contains("mul(2,4)"));
+        assert!(inputs.get(2).contains("don't()"));
+        assert_ne!(inputs.get(1), inputs.get(2));
+
+        let inputs = InputSource::Example
+            .read(Puzzle::new(2024, day!(1)))
+            .unwrap();
+        assert_eq!(inputs.get(1), inputs.get(2));
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let error = InputSource::File("does/not/exist.txt".into())
+            .read(Puzzle::new(2024, day!(1)))
+            .unwrap_err();
+        assert!(error.starts_with("could not open input file \"does/not/exist.txt\""));
+    }
+}
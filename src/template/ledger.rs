@@ -0,0 +1,318 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{submission::SubmissionOutcome, year_data_dir, Day};
+
+fn get_file_path(year: u16) -> String {
+    format!("./{}/submissions.json", year_data_dir(year))
+}
+
+/// A single answer that was checked by the website.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+}
+
+/// Reasons for refusing to submit an answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved(String),
+    KnownWrong,
+    NotBelow(i128),
+    NotAbove(i128),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with answer {answer}.")
+            }
+            Rejection::KnownWrong => write!(f, "this answer was already submitted and is wrong."),
+            Rejection::NotBelow(bound) => write!(f, "{bound} was too high, answer must be lower."),
+            Rejection::NotAbove(bound) => write!(f, "{bound} was too low, answer must be higher."),
+        }
+    }
+}
+
+/// Every answer submitted so far for the puzzles of a year. Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub data: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Dehydrate the ledger of a year to a JSON file.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(year_data_dir(year))?;
+        let mut file = fs::File::create(get_file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the ledger of a year from a JSON file. If not present, returns an empty ledger.
+    /// Fails if the file exists but can not be read or parsed, so that it is never overwritten.
+    pub fn read_from_file(year: u16) -> Result<Self, String> {
+        let path = get_file_path(year);
+
+        match fs::read_to_string(&path) {
+            Ok(content) => Ledger::try_from(content).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(format!("{path}: {e}")),
+        }
+    }
+
+    /// Records a submission. Outcomes where the answer was not checked are ignored.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, outcome: &SubmissionOutcome) {
+        if outcome.is_checked() {
+            self.data.push(Submission {
+                day,
+                part,
+                answer: answer.into(),
+                outcome: outcome.clone(),
+            });
+        }
+    }
+
+    /// The accepted answer for a part, if it was solved.
+    pub fn correct_answer(&self, day: Day, part: u8) -> Option<&str> {
+        self.submissions(day, part)
+            .find(|s| s.outcome == SubmissionOutcome::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Checks whether submitting `answer` can possibly be worth it.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Rejection> {
+        if let Some(correct) = self.correct_answer(day, part) {
+            return Err(Rejection::AlreadySolved(correct.into()));
+        }
+
+        if self.submissions(day, part).any(|s| s.answer == answer) {
+            return Err(Rejection::KnownWrong);
+        }
+
+        // bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |outcome: SubmissionOutcome| {
+            self.submissions(day, part)
+                .filter(move |s| s.outcome == outcome)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(SubmissionOutcome::TooHigh).min() {
+            if value >= upper {
+                return Err(Rejection::NotBelow(upper));
+            }
+        }
+
+        if let Some(lower) = bound(SubmissionOutcome::TooLow).max() {
+            if value <= lower {
+                return Err(Rejection::NotAbove(lower));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn submissions(&self, day: Day, part: u8) -> impl Iterator<Item = &Submission> {
+        self.data
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn outcome_to_str(outcome: &SubmissionOutcome) -> &'static str {
+    match outcome {
+        SubmissionOutcome::Correct => "correct",
+        SubmissionOutcome::TooHigh => "too_high",
+        SubmissionOutcome::TooLow => "too_low",
+        _ => "incorrect",
+    }
+}
+
+fn outcome_from_str(s: &str) -> Option<SubmissionOutcome> {
+    match s {
+        "correct" => Some(SubmissionOutcome::Correct),
+        "too_high" => Some(SubmissionOutcome::TooHigh),
+        "too_low" => Some(SubmissionOutcome::TooLow),
+        "incorrect" => Some(SubmissionOutcome::Incorrect),
+        _ => None,
+    }
+}
+
+impl From<Ledger> for JsonValue {
+    fn from(value: Ledger) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Ledger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Ledger {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(outcome_to_str(&value.outcome).into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|&&part| part == 1.0 || part == 2.0)
+            .map(|&part| part as u8)
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .and_then(|outcome| outcome_from_str(outcome))
+            .ok_or("Expected submission.outcome to be a known outcome.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Ledger, Rejection};
+    use crate::{day, template::submission::SubmissionOutcome};
+
+    fn get_mock_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "100", &SubmissionOutcome::TooHigh);
+        ledger.record(day!(1), 1, "10", &SubmissionOutcome::TooLow);
+        ledger.record(day!(1), 1, "50", &SubmissionOutcome::Incorrect);
+        ledger.record(day!(2), 1, "42", &SubmissionOutcome::Correct);
+        ledger
+    }
+
+    #[test]
+    fn ignores_unchecked_outcomes() {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "1", &SubmissionOutcome::RateLimited(None));
+        ledger.record(day!(1), 1, "1", &SubmissionOutcome::AlreadyCompleted);
+        assert!(ledger.data.is_empty());
+    }
+
+    #[test]
+    fn rejects_known_answers() {
+        let ledger = get_mock_ledger();
+        assert_eq!(ledger.check(day!(1), 1, "50"), Err(Rejection::KnownWrong));
+        assert_eq!(
+            ledger.check(day!(2), 1, "43"),
+            Err(Rejection::AlreadySolved("42".into()))
+        );
+        assert_eq!(ledger.correct_answer(day!(2), 1), Some("42"));
+    }
+
+    #[test]
+    fn rejects_answers_out_of_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(ledger.check(day!(1), 1, "100"), Err(Rejection::KnownWrong));
+        assert_eq!(
+            ledger.check(day!(1), 1, "120"),
+            Err(Rejection::NotBelow(100))
+        );
+        assert_eq!(ledger.check(day!(1), 1, "3"), Err(Rejection::NotAbove(10)));
+        assert_eq!(ledger.check(day!(1), 1, "51"), Ok(()));
+        assert_eq!(ledger.check(day!(1), 1, "abc"), Ok(()));
+        assert_eq!(ledger.check(day!(1), 2, "120"), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let ledger = get_mock_ledger();
+        let json = tinyjson::JsonValue::from(ledger.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Ledger::try_from(json).unwrap();
+        assert_eq!(parsed.data, ledger.data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_submissions() {
+        let json =
+            r#"{ "data": [{ "day": "01", "part": 3, "answer": "1", "outcome": "correct" }] }"#
+                .to_string();
+        Ledger::try_from(json).unwrap();
+    }
+}
@@ -0,0 +1,9 @@
+/// The highest day number of any event as a literal, e.g. for messages built with `concat!`.
+/// Use [`MAX_DAY`](crate::template::MAX_DAY) elsewhere. `build.rs` includes this file as it can not depend on the crate it builds.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! max_day {
+    () => {
+        25
+    };
+}
@@ -1,37 +1,51 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod aoc_client;
 pub mod commands;
+pub mod example_manifest;
+pub mod input;
+pub mod ocr;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod submission;
 
 pub use day::*;
+pub use puzzle::*;
 
 mod day;
+mod examples;
+mod ledger;
+mod max_day;
+mod puzzle;
+mod puzzle_markdown;
 mod readme_benchmarks;
 mod run_multi;
+mod timing_history;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of the puzzle's year to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(year_data_dir(puzzle.year))
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
@@ -39,30 +53,112 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The binary reads the puzzle input unless `--example`, `--input <path>` or `--stdin` select another
+/// [`input::InputSource`].
+///
+/// Alternative implementations of a part can be registered as variants, which `cargo solve <day> --variants`
+/// runs side by side, e.g. `solution!(6, variants { 2 => [part_two, part_two_brute_force] })`.
+///
+/// Every part gets a test that checks it against the examples listed in the day's [`example_manifest`].
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2], variants {});
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [part_one, 1], variants {});
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [part_two, 2], variants {});
+    };
+    ($day:expr, variants { $( $vpart:literal => [$( $variant:ident ),+ $(,)?] ),* $(,)? }) => {
+        $crate::solution!(
+            @impl $day, [part_one, 1] [part_two, 2],
+            variants { $( $vpart => [$( $variant ),+] )* }
+        );
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, $( [$func:ident, $part:expr] )*, variants { $( $vpart:literal => [$( $variant:ident ),+] )* }) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The current puzzle, its year is taken from the name of the solution file.
+        pub const PUZZLE: $crate::template::Puzzle =
+            $crate::template::Puzzle::__from_bin_path(file!(), DAY);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        /// Runs every part in-process, used by the solution registry of the runner.
+        #[allow(dead_code)]
+        pub fn run_parts(
+            input: &str,
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::report::PartReport> {
+            use $crate::template::runner::*;
+            vec![$( run_part_with($func, input, $part, options) ),*]
+        }
+
+        /// Runs every registered variant of a part and compares them. Returns whether all variants agree.
+        #[allow(unused_mut, unused_variables)]
+        fn run_variants(inputs: &$crate::template::input::Inputs) -> bool {
+            use $crate::template::runner::*;
+            let options = RunOptions {
+                is_timed: true,
+                ..RunOptions::from_args()
+            };
+
+            let mut is_agreeing = true;
+            let mut compared_parts = 0;
+            $(
+                compared_parts += 1;
+                is_agreeing &= compare_variants(
+                    &[$( Variant::new(stringify!($variant), $variant) ),+],
+                    inputs.get($vpart),
+                    $vpart,
+                    &options,
+                );
+            )*
+
+            if compared_parts == 0 {
+                eprintln!("No variants registered, see the `solution!` macro.");
+            }
+            is_agreeing
+        }
+
+        /// Checks every part against the examples listed in the example manifest of the day.
+        #[cfg(test)]
+        mod example_tests {
+            $(
+                #[test]
+                fn $func() {
+                    $crate::template::example_manifest::check_examples(
+                        super::$func,
+                        super::PUZZLE,
+                        $part,
+                    );
+                }
+            )*
+        }
+
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let inputs = match $crate::template::input::InputSource::from_args().read(PUZZLE) {
+                Ok(inputs) => inputs,
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            };
+            if std::env::args().any(|x| x == "--variants") {
+                std::process::exit(if run_variants(&inputs) { 0 } else { 1 });
+            }
+            let submissions = [$( run_part($func, inputs.get($part), PUZZLE, $part) ),*];
+            if let Some(submission) = submissions.into_iter().flatten().next() {
+                std::process::exit(submission_exit_code(&submission));
+            }
         }
     };
 }
@@ -0,0 +1,225 @@
+/// Recognizes answers that are drawn as block letters, e.g. the display of 2016 day 8 or the code of 2022 day 10.
+/// Lit pixels are `#` or `█`, every other character is a dark pixel. Letters are separated by dark columns.
+use std::collections::HashMap;
+
+/// The font most puzzles draw with: 6 rows, letters up to 5 pixels wide.
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.|#..#|#..#|####|#..#|#..#"),
+    ('B', "###.|#..#|###.|#..#|#..#|###."),
+    ('C', ".##.|#..#|#...|#...|#..#|.##."),
+    ('E', "####|#...|###.|#...|#...|####"),
+    ('F', "####|#...|###.|#...|#...|#..."),
+    ('G', ".##.|#..#|#...|#.##|#..#|.###"),
+    ('H', "#..#|#..#|####|#..#|#..#|#..#"),
+    ('I', "###|.#.|.#.|.#.|.#.|###"),
+    ('J', "..##|...#|...#|...#|#..#|.##."),
+    ('K', "#..#|#.#.|##..|#.#.|#.#.|#..#"),
+    ('L', "#...|#...|#...|#...|#...|####"),
+    ('O', ".##.|#..#|#..#|#..#|#..#|.##."),
+    ('P', "###.|#..#|#..#|###.|#...|#..."),
+    ('R', "###.|#..#|#..#|###.|#.#.|#..#"),
+    ('S', ".###|#...|#...|.##.|...#|###."),
+    ('U', "#..#|#..#|#..#|#..#|#..#|.##."),
+    ('Y', "#...#|#...#|.#.#.|..#..|..#..|..#.."),
+    ('Z', "####|...#|..#.|.#..|#...|####"),
+];
+
+/// The font of some of the earlier puzzles: 10 rows, letters 6 pixels wide.
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..|.#..#.|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#",
+    ),
+    (
+        'B',
+        "#####.|#....#|#....#|#....#|#####.|#....#|#....#|#....#|#....#|#####.",
+    ),
+    (
+        'C',
+        ".####.|#....#|#.....|#.....|#.....|#.....|#.....|#.....|#....#|.####.",
+    ),
+    (
+        'E',
+        "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|######",
+    ),
+    (
+        'F',
+        "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|#.....",
+    ),
+    (
+        'G',
+        ".####.|#....#|#.....|#.....|#.....|#..###|#....#|#....#|#...##|.###.#",
+    ),
+    (
+        'H',
+        "#....#|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#|#....#",
+    ),
+    (
+        'J',
+        "...###|....#.|....#.|....#.|....#.|....#.|....#.|#...#.|#...#.|.###..",
+    ),
+    (
+        'K',
+        "#....#|#...#.|#..#..|#.#...|##....|##....|#.#...|#..#..|#...#.|#....#",
+    ),
+    (
+        'L',
+        "#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|######",
+    ),
+    (
+        'N',
+        "#....#|##...#|##...#|#.#..#|#.#..#|#..#.#|#..#.#|#...##|#...##|#....#",
+    ),
+    (
+        'P',
+        "#####.|#....#|#....#|#....#|#####.|#.....|#.....|#.....|#.....|#.....",
+    ),
+    (
+        'R',
+        "#####.|#....#|#....#|#....#|#####.|#..#..|#...#.|#...#.|#....#|#....#",
+    ),
+    (
+        'X',
+        "#....#|#....#|.#..#.|.#..#.|..##..|..##..|.#..#.|.#..#.|#....#|#....#",
+    ),
+    (
+        'Z',
+        "######|.....#|.....#|....#.|...#..|..#...|.#....|#.....|#.....|######",
+    ),
+];
+
+/// A glyph as rows of pixels, trimmed to its lit columns.
+type Glyph = Vec<Vec<bool>>;
+
+/// Reads the letters drawn by `art`. Returns [`None`] if `art` is not a single line of known letters.
+pub fn read_letters(art: &str) -> Option<String> {
+    let mut rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+
+    // drop dark rows around the letters.
+    while rows.last().is_some_and(|row| !row.contains(&true)) {
+        rows.pop();
+    }
+    let first_lit = rows.iter().position(|row| row.contains(&true))?;
+    rows.drain(..first_lit);
+
+    let font = match rows.len() {
+        6 => glyphs(&SMALL_FONT),
+        10 => glyphs(&LARGE_FONT),
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let is_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if !is_lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && is_lit(x) {
+            x += 1;
+        }
+
+        let glyph: Glyph = rows
+            .iter()
+            .map(|row| (start..x).map(|x| row.get(x) == Some(&true)).collect())
+            .collect();
+        letters.push(*font.get(&glyph)?);
+    }
+
+    Some(letters)
+}
+
+fn glyphs(font: &[(char, &str)]) -> HashMap<Glyph, char> {
+    font.iter()
+        .map(|&(letter, pattern)| (trim(parse_glyph(pattern)), letter))
+        .collect()
+}
+
+fn parse_glyph(pattern: &str) -> Glyph {
+    pattern
+        .split('|')
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect()
+}
+
+/// Removes dark columns on either side of a glyph, the fonts pad some of their letters.
+fn trim(glyph: Glyph) -> Glyph {
+    let is_lit = |x: usize| glyph.iter().any(|row| row[x]);
+    let width = glyph[0].len();
+    let start = (0..width).find(|&x| is_lit(x)).unwrap_or(0);
+    let end = (0..width).rfind(|&x| is_lit(x)).map_or(width, |x| x + 1);
+
+    glyph.iter().map(|row| row[start..end].to_vec()).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::read_letters;
+
+    #[test]
+    fn reads_small_letters() {
+        let art = "\
+#..#.####.###..###...##.
+#..#.#....#..#.#..#.#..#
+####.###..###..#..#.#..#
+#..#.#....#..#.###..#..#
+#..#.#....#..#.#.#..#..#
+#..#.####.###..#..#..##.
+";
+        assert_eq!(read_letters(art), Some("HEBRO".into()));
+    }
+
+    #[test]
+    fn reads_narrow_and_wide_letters() {
+        let art = "\
+###..#...#
+.#...#...#
+.#....#.#.
+.#.....#..
+.#.....#..
+###....#..";
+        assert_eq!(read_letters(art), Some("IY".into()));
+    }
+
+    #[test]
+    fn reads_large_letters() {
+        let art = "
+#....#..######
+#....#..#.....
+.#..#...#.....
+.#..#...#.....
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..######
+
+";
+        assert_eq!(read_letters(art), Some("XE".into()));
+    }
+
+    #[test]
+    fn reads_other_pixels() {
+        let art = "███ \n█  █\n█  █\n███ \n█   \n█   ";
+        assert_eq!(read_letters(art), Some("P".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_art() {
+        assert_eq!(read_letters("#.#\n.#.\n#.#"), None);
+        assert_eq!(read_letters("#\n#\n#\n#\n#\n#"), None);
+        assert_eq!(read_letters("12345"), None);
+    }
+}
@@ -0,0 +1,250 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{days_in_year, Day};
+
+/// The event year configured via `AOC_YEAR` when the template was compiled.
+const COMPILED_YEAR: Option<u16> = match option_env!("AOC_YEAR") {
+    Some(year) => parse_year(year.as_bytes(), 0),
+    None => None,
+};
+
+/// The event year used when none is given explicitly, read from `AOC_YEAR`.
+///
+/// # Panics
+/// If `AOC_YEAR` is not set to a valid year.
+pub fn default_year() -> u16 {
+    env::var("AOC_YEAR")
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .or(COMPILED_YEAR)
+        .expect("AOC_YEAR is not set to a valid year.")
+}
+
+/// Directory that holds the data of an event year, e.g. `data/2024`.
+pub fn year_data_dir(year: u16) -> String {
+    format!("data/{year}")
+}
+
+/// A puzzle of an Advent of Code event, identified by its year and day.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of its solution binary.
+///
+/// ```
+/// # use advent_of_code::{day, template::Puzzle};
+/// let puzzle = Puzzle::new(2023, day!(8));
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub fn new(year: u16, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates a [`Puzzle`] if `day` is one of the days of the event in `year`, returns [`None`] otherwise.
+    pub fn try_new(year: u16, day: Day) -> Option<Self> {
+        (day <= days_in_year(year)).then_some(Self { year, day })
+    }
+
+    /// The puzzle of `day` in the [`default_year`].
+    pub fn of_default_year(day: Day) -> Self {
+        Self::new(default_year(), day)
+    }
+
+    // Not part of the public API
+    // Takes the year from solution files named `<year>-<day>.rs`, falling back to `AOC_YEAR`.
+    #[doc(hidden)]
+    pub const fn __from_bin_path(path: &str, day: Day) -> Self {
+        let bytes = path.as_bytes();
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+            start -= 1;
+        }
+
+        let year = match parse_year(bytes, start) {
+            Some(year) if bytes.len() > start + 4 && bytes[start + 4] == b'-' => year,
+            _ => match COMPILED_YEAR {
+                Some(year) => year,
+                None => {
+                    panic!("solution files must be named `<year>-<day>.rs` or AOC_YEAR must be set")
+                }
+            },
+        };
+
+        Self { year, day }
+    }
+
+    /// Path of the solution file, e.g. `./src/bin/2024-05.rs`.
+    pub fn bin_path(&self) -> String {
+        format!("./src/bin/{self}.rs")
+    }
+
+    /// Path of a data file of this puzzle, e.g. `data/2024/inputs/05.txt`.
+    pub fn data_path(&self, folder: &str, extension: &str) -> String {
+        format!(
+            "{}/{folder}/{}.{extension}",
+            year_data_dir(self.year),
+            self.day
+        )
+    }
+
+    pub fn input_path(&self) -> String {
+        self.data_path("inputs", "txt")
+    }
+
+    pub fn example_path(&self) -> String {
+        self.data_path("examples", "txt")
+    }
+
+    /// Path of the example of a part that has its own example, e.g. `data/2024/examples/05-2.txt`.
+    pub fn example_part_path(&self, part: u8) -> String {
+        format!(
+            "{}/examples/{}-{part}.txt",
+            year_data_dir(self.year),
+            self.day
+        )
+    }
+
+    /// Path of the example test cases, e.g. `data/2024/examples/05.json`.
+    pub fn example_manifest_path(&self) -> String {
+        self.data_path("examples", "json")
+    }
+
+    pub fn puzzle_path(&self) -> String {
+        self.data_path("puzzles", "md")
+    }
+}
+
+/// Parses the four digit year starting at `start`.
+const fn parse_year(bytes: &[u8], start: usize) -> Option<u16> {
+    if bytes.len() < start + 4 {
+        return None;
+    }
+
+    let mut year = 0;
+    let mut i = start;
+    while i < start + 4 {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Some(year)
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Puzzle {
+    type Err = PuzzleFromStrError;
+
+    /// Parses `<day>`, `<year>-<day>` or `<year>/<day>`. Without a year, the [`default_year`] is used.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = match s.split_once(['-', '/']) {
+            Some((year, day)) => (
+                year.parse().map_err(|_| PuzzleFromStrError::Malformed)?,
+                day,
+            ),
+            None => (default_year(), s),
+        };
+
+        let day: u8 = day.parse().map_err(|_| PuzzleFromStrError::Malformed)?;
+        Day::new(day)
+            .and_then(|day| Self::try_new(year, day))
+            .ok_or(PuzzleFromStrError::DayOutOfRange {
+                year,
+                days: days_in_year(year),
+            })
+    }
+}
+
+/// An error which can be returned when parsing a [`Puzzle`].
+#[derive(Debug)]
+pub enum PuzzleFromStrError {
+    Malformed,
+    DayOutOfRange { year: u16, days: u8 },
+}
+
+impl Error for PuzzleFromStrError {}
+
+impl Display for PuzzleFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed => f.write_str(
+                "expecting a day number, optionally prefixed with a year like `2023-05`",
+            ),
+            Self::DayOutOfRange { year, days } => {
+                write!(f, "expecting a day number between 1 and {days} for {year}")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Puzzle;
+    use crate::day;
+
+    #[test]
+    fn parses_puzzles() {
+        assert_eq!(
+            "2023-05".parse::<Puzzle>().unwrap(),
+            Puzzle::new(2023, day!(5))
+        );
+        assert_eq!(
+            "2023/5".parse::<Puzzle>().unwrap(),
+            Puzzle::new(2023, day!(5))
+        );
+        assert_eq!("5".parse::<Puzzle>().unwrap().day, day!(5));
+        assert!("2023-26".parse::<Puzzle>().is_err());
+        assert!("abcd-05".parse::<Puzzle>().is_err());
+    }
+
+    #[test]
+    fn rejects_days_after_the_event() {
+        assert_eq!(
+            "2025-12".parse::<Puzzle>().unwrap(),
+            Puzzle::new(2025, day!(12))
+        );
+        let err = "2025-13".parse::<Puzzle>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expecting a day number between 1 and 12 for 2025"
+        );
+        assert_eq!(Puzzle::try_new(2025, day!(20)), None);
+    }
+
+    #[test]
+    fn reads_year_from_bin_path() {
+        let puzzle = Puzzle::__from_bin_path("/repo/src/bin/2015-03.rs", day!(3));
+        assert_eq!(puzzle, Puzzle::new(2015, day!(3)));
+
+        let puzzle = Puzzle::__from_bin_path("src\\bin\\2016-04.rs", day!(4));
+        assert_eq!(puzzle, Puzzle::new(2016, day!(4)));
+    }
+
+    #[test]
+    fn builds_paths() {
+        let puzzle = Puzzle::new(2023, day!(5));
+        assert_eq!(puzzle.bin_path(), "./src/bin/2023-05.rs");
+        assert_eq!(puzzle.input_path(), "data/2023/inputs/05.txt");
+        assert_eq!(puzzle.puzzle_path(), "data/2023/puzzles/05.md");
+    }
+}
@@ -0,0 +1,161 @@
+//! Converts Advent of Code puzzle pages to markdown.
+//! Only the `<article>` elements holding the puzzle description are kept, and only the
+//! handful of tags used on the site are translated.
+
+#[derive(Default)]
+struct Converter {
+    out: String,
+    /// Inline code currently being collected, with a flag whether it was emphasized.
+    code: Option<(String, bool)>,
+    in_pre: bool,
+}
+
+impl Converter {
+    fn open(&mut self, tag: &str) {
+        match tag {
+            "h2" => self.out.push_str("## "),
+            "pre" => {
+                self.in_pre = true;
+                self.out.push_str("```\n");
+            }
+            "code" if !self.in_pre => self.code = Some((String::new(), false)),
+            "em" => match &mut self.code {
+                Some((_, emphasized)) => *emphasized = true,
+                None if !self.in_pre => self.out.push('*'),
+                None => {}
+            },
+            "li" => self.out.push_str("- "),
+            "br" => self.out.push('\n'),
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, tag: &str) {
+        match tag {
+            "h2" | "p" => self.out.push_str("\n\n"),
+            "li" | "ul" => self.out.push('\n'),
+            "pre" => {
+                self.in_pre = false;
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("```\n\n");
+            }
+            "code" => {
+                if let Some((code, emphasized)) = self.code.take() {
+                    if emphasized {
+                        self.out.push_str(&format!("*`{code}`*"));
+                    } else {
+                        self.out.push_str(&format!("`{code}`"));
+                    }
+                }
+            }
+            "em" if self.code.is_none() && !self.in_pre => self.out.push('*'),
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = decode_entities(text);
+        match &mut self.code {
+            Some((code, _)) => code.push_str(&text),
+            None => self.out.push_str(&text),
+        }
+    }
+}
+
+/// Extracts the puzzle description articles from `html` and renders them as markdown.
+pub fn from_html(html: &str) -> String {
+    let mut converter = Converter::default();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let article = &rest[start..start + end];
+        rest = &rest[start + end + "</article>".len()..];
+
+        // skip the opening `<article ...>` tag itself.
+        let body = article.split_once('>').map_or("", |(_, body)| body);
+        convert_fragment(&mut converter, body);
+    }
+
+    let mut markdown = converter.out.trim_end().to_string();
+    markdown.push('\n');
+    markdown
+}
+
+fn convert_fragment(converter: &mut Converter, html: &str) {
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        converter.text(&rest[..tag_start]);
+
+        let Some(tag_end) = rest[tag_start..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_start + tag_end];
+        rest = &rest[tag_start + tag_end + 1..];
+
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if tag.starts_with('/') {
+            converter.close(&name);
+        } else {
+            converter.open(&name);
+        }
+    }
+
+    converter.text(rest);
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::from_html;
+
+    #[test]
+    fn ignores_content_outside_articles() {
+        let html = "<p>nav</p><article class=\"day-desc\"><p>inside</p></article><p>footer</p>";
+        assert_eq!(from_html(html), "inside\n");
+    }
+
+    #[test]
+    fn converts_code_blocks() {
+        let html = "<article><p>Example:</p><pre><code>3   4\n4   3\n</code></pre></article>";
+        assert_eq!(from_html(html), "Example:\n\n```\n3   4\n4   3\n```\n");
+    }
+
+    #[test]
+    fn converts_emphasized_code() {
+        let html = "<article><p>The total is <code><em>11</em></code> and <code>x&lt;y</code>.</p></article>";
+        assert_eq!(from_html(html), "The total is *`11`* and `x<y`.\n");
+    }
+
+    #[test]
+    fn converts_lists_and_multiple_articles() {
+        let html = "<article><h2>--- Day 1 ---</h2><ul><li>a</li><li>b</li></ul></article><p>Your puzzle answer was <code>1</code>.</p><article><h2 id=\"part2\">--- Part Two ---</h2></article>";
+        assert_eq!(
+            from_html(html),
+            "## --- Day 1 ---\n\n- a\n- b\n\n## --- Part Two ---\n"
+        );
+    }
+}
@@ -1,19 +1,35 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io};
 
+use crate::template::alloc::{self, MemoryUsage};
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{days_in_year, default_year, Puzzle};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Every year has its own table, enclosed by a pair of markers.
+fn get_marker(year: u16) -> String {
+    format!("<!--- benchmarking table {year} --->")
+}
+
+/// Marker of readmes from before tables were kept per year.
+/// It is still accepted for the default year and replaced by the marker of that year on update.
+const LEGACY_MARKER: &str = "<!--- benchmarking table --->";
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -25,13 +41,8 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,55 +63,123 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn format_memory(memory: Option<MemoryUsage>) -> String {
+    memory.map_or("-".into(), |m| {
+        format!(
+            "`{}` / {} allocs",
+            alloc::format_bytes(m.peak_bytes),
+            m.allocations
+        )
+    })
+}
+
+fn construct_table(prefix: &str, year: u16, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
+    let marker = get_marker(year);
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // memory columns are only shown once memory was measured for any day.
+    let has_memory = timings
+        .data
+        .iter()
+        .any(|t| t.part_1_memory.is_some() || t.part_2_memory.is_some());
+
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+
+    if has_memory {
+        lines.push("| Day | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |".into());
+        lines.push("| :---: | :---: | :---:  | :---: | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let path = Puzzle::new(year, timing.day).bin_path();
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+
+        if has_memory {
+            line.push_str(&format!(
+                " {} | {} |",
+                format_memory(timing.part_1_memory),
+                format_memory(timing.part_2_memory)
+            ));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+/// Locates the table of `year`, falling back to the legacy marker if `accepts_legacy` is set.
+fn locate_year_table(
+    readme: &str,
+    year: u16,
+    accepts_legacy: bool,
+) -> Result<TablePosition, Error> {
+    let marker = get_marker(year);
+
+    if readme.contains(&marker) || !accepts_legacy || !readme.contains(LEGACY_MARKER) {
+        return locate_table(readme, &marker).map_err(|_| {
+            Error::Parser(format!(
+                "Could not find the benchmark table of {year}, add a pair of `{marker}` markers to the README."
+            ))
+        });
+    }
+
+    locate_table(readme, LEGACY_MARKER)
+}
+
+fn update_content(
+    s: &mut String,
+    year: u16,
+    timings: Timings,
+    total_millis: f64,
+    accepts_legacy: bool,
+) -> Result<(), Error> {
+    let positions = locate_year_table(s, year, accepts_legacy)?;
+    let table = construct_table("##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: u16, mut timings: Timings) -> Result<(), Error> {
     let path = "README.md";
+    // drop stale timings of days that are not part of the event.
+    let days = days_in_year(year);
+    timings.data.retain(|timing| timing.day <= days);
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(
+        &mut readme,
+        year,
+        timings,
+        total_millis,
+        year == default_year(),
+    )?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{construct_table, update_content};
+    use crate::{
+        day,
+        template::alloc::MemoryUsage,
+        template::runner::Statistic,
+        template::timings::{Timing, Timings},
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -109,75 +188,157 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_nanos: Some(10_000_000),
+                    part_2_nanos: Some(20_000_000),
                     total_nanos: 3e+10,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_nanos: Some(30_000_000),
+                    part_2_nanos: Some(40_000_000),
                     total_nanos: 7e+10,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_nanos: Some(40_000_000),
+                    part_2_nanos: Some(50_000_000),
                     total_nanos: 9e+10,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 },
             ],
         }
     }
 
+    static MARKER: &str = "<!--- benchmarking table 2024 --->";
+
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
+        assert_eq!(s.contains("## 2024 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        assert_eq!(
+            s.matches("## 2024 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn migrates_legacy_marker() {
+        let legacy = "<!--- benchmarking table --->";
+        let mut s = format!("foo\n{legacy}\nold\n{legacy}\nbaz");
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
+        assert_eq!(s.contains(legacy), false);
+        assert_eq!(s.contains("old"), false);
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.ends_with(&format!("{MARKER}\nbaz")), true);
+    }
+
+    #[test]
+    fn prefers_marker_of_year_over_legacy_marker() {
+        let legacy = "<!--- benchmarking table --->";
+        let mut s = format!("{legacy}\nold\n{legacy}\n{MARKER}{MARKER}");
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
+        assert_eq!(s.starts_with(&format!("{legacy}\nold\n{legacy}")), true);
+    }
+
+    #[test]
+    fn ignores_legacy_marker_for_other_years() {
+        let legacy = "<!--- benchmarking table --->";
+        let mut s = format!("{legacy}\nold\n{legacy}");
+        let res = update_content(&mut s, 2023, get_mock_timings(), 190.0, false);
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .contains("<!--- benchmarking table 2023 --->"));
+    }
+
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let other = "<!--- benchmarking table 2023 --->";
+        let mut s = format!("{MARKER}{MARKER}\n{other}\nold\n{other}");
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
+        assert_eq!(s.contains(&format!("{other}\nold\n{other}")), true);
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2024, get_mock_timings(), 190.0, true).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2024 --->",
+            "## 2024 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2024 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_memory_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_memory = Some(MemoryUsage {
+            peak_bytes: 1536,
+            allocations: 12,
+        });
+
+        let table = construct_table("##", 2024, timings, 190.0);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` | `1.5 KiB` / 12 allocs | - |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` | - | - |"
+        );
+    }
 }
@@ -0,0 +1,34 @@
+/// Solutions compiled into the runner binary so they can be called without spawning `cargo run`.
+/// The runner's build script generates one [`Solution`] per `src/bin/<year>-<day>.rs`.
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::template::{report::PartReport, runner::RunOptions, Puzzle};
+
+/// A solution that can be run in-process.
+pub struct Solution {
+    pub puzzle: Puzzle,
+    /// Runs every part of the solution against an input. Generated by [`crate::solution`].
+    pub run: fn(&str, &RunOptions) -> Vec<PartReport>,
+}
+
+impl Solution {
+    /// Runs the solution against its puzzle input.
+    /// Fails if the input is missing or the solution panicked.
+    pub fn run_with_input(&self, options: &RunOptions) -> Result<Vec<PartReport>, String> {
+        let Ok(input) = fs::read_to_string(self.puzzle.input_path()) else {
+            return Err(format!("could not open input file for {}.", self.puzzle));
+        };
+
+        // the panic message itself is printed by the panic hook.
+        panic::catch_unwind(AssertUnwindSafe(|| (self.run)(&input, options)))
+            .map_err(|_| format!("solution for {} panicked.", self.puzzle))
+    }
+}
+
+/// Finds the solution for a puzzle in a registry.
+pub fn find(solutions: &[Solution], puzzle: Puzzle) -> Option<&Solution> {
+    solutions.iter().find(|s| s.puzzle == puzzle)
+}
@@ -0,0 +1,274 @@
+/// Machine-readable records that solution binaries emit for the runner.
+/// When a solution is invoked with `--report <path>`, it appends one JSON object per part to `<path>`.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    alloc::MemoryUsage,
+    runner::{BenchStats, Statistic},
+};
+
+/// The result of running one part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub part: u8,
+    /// The answer, [`None`] if the part is not implemented.
+    pub answer: Option<String>,
+    /// The reported statistic of the execution time in nanoseconds.
+    pub nanos: f64,
+    pub statistic: Statistic,
+    /// Every statistic of the execution time, so that buffered output can show the same as a run that prints.
+    pub stats: BenchStats,
+    pub error: Option<String>,
+    /// Heap usage of the first execution, if it was measured.
+    pub memory: Option<MemoryUsage>,
+}
+
+impl PartReport {
+    /// Appends the report as a single JSON line to the file at `path`.
+    pub fn append_to(&self, path: &Path) -> Result<(), io::Error> {
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")
+    }
+}
+
+/// Reads all reports from the file at `path`. If not present, returns no reports.
+pub fn read_reports(path: &Path) -> Result<Vec<PartReport>, String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).map_err(|_| "not a valid JSON line.".to_string())?;
+            PartReport::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+fn stats_to_json(stats: &BenchStats) -> JsonValue {
+    let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert("mean_nanos".into(), nanos(stats.mean));
+    map.insert("median_nanos".into(), nanos(stats.median));
+    map.insert("min_nanos".into(), nanos(stats.min));
+    map.insert("max_nanos".into(), nanos(stats.max));
+    map.insert("std_dev_nanos".into(), nanos(stats.std_dev));
+    map.insert("samples".into(), JsonValue::Number(stats.samples as f64));
+    map.insert("outliers".into(), JsonValue::Number(stats.outliers as f64));
+
+    JsonValue::Object(map)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn stats_from_json(value: Option<&JsonValue>) -> Result<BenchStats, String> {
+    let map = value
+        .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+        .ok_or("Expected report.stats to be an object.")?;
+
+    let number = |key: &str| -> Result<f64, String> {
+        map.get(key)
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or(format!("Expected report.stats.{key} to be a number."))
+    };
+    let duration = |key: &str| number(key).map(|n| Duration::from_nanos(n as u64));
+
+    Ok(BenchStats {
+        mean: duration("mean_nanos")?,
+        median: duration("median_nanos")?,
+        min: duration("min_nanos")?,
+        max: duration("max_nanos")?,
+        std_dev: duration("std_dev_nanos")?,
+        samples: number("samples")? as u128,
+        outliers: number("outliers")? as usize,
+    })
+}
+
+fn optional_string(value: Option<&String>) -> JsonValue {
+    match value {
+        Some(s) => JsonValue::String(s.clone()),
+        None => JsonValue::Null,
+    }
+}
+
+impl From<&PartReport> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), optional_string(value.answer.as_ref()));
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert("stats".into(), stats_to_json(&value.stats));
+        map.insert(
+            "statistic".into(),
+            JsonValue::String(value.statistic.to_string()),
+        );
+        map.insert("error".into(), optional_string(value.error.as_ref()));
+        map.insert(
+            "peak_bytes".into(),
+            value
+                .memory
+                .map_or(JsonValue::Null, |m| JsonValue::Number(m.peak_bytes as f64)),
+        );
+        map.insert(
+            "allocations".into(),
+            value
+                .memory
+                .map_or(JsonValue::Null, |m| JsonValue::Number(m.allocations as f64)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let optional_string = |key: &str| -> Result<Option<String>, String> {
+            match json.get(key) {
+                Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+                Some(JsonValue::Null) | None => Ok(None),
+                Some(_) => Err(format!("Expected report.{key} to be null or string.")),
+            }
+        };
+
+        let number = |key: &str| -> Result<f64, String> {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected report.{key} to be a number."))
+        };
+
+        let part = number("part")?;
+        if part != 1.0 && part != 2.0 {
+            return Err("Expected report.part to be 1 or 2.".into());
+        }
+
+        let statistic = json
+            .get("statistic")
+            .and_then(|v| v.get::<String>())
+            .and_then(|s| s.parse().ok())
+            .ok_or("Expected report.statistic to be a known statistic.")?;
+
+        let optional_number = |key: &str| -> Result<Option<u64>, String> {
+            match json.get(key) {
+                Some(JsonValue::Number(n)) => Ok(Some(*n as u64)),
+                Some(JsonValue::Null) | None => Ok(None),
+                Some(_) => Err(format!("Expected report.{key} to be null or number.")),
+            }
+        };
+
+        let memory = match (
+            optional_number("peak_bytes")?,
+            optional_number("allocations")?,
+        ) {
+            (Some(peak_bytes), Some(allocations)) => Some(MemoryUsage {
+                peak_bytes,
+                allocations,
+            }),
+            _ => None,
+        };
+
+        Ok(PartReport {
+            part: part as u8,
+            answer: optional_string("answer")?,
+            nanos: number("nanos")?,
+            statistic,
+            stats: stats_from_json(json.get("stats"))?,
+            error: optional_string("error")?,
+            memory,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{read_reports, PartReport};
+    use crate::template::{
+        alloc::MemoryUsage,
+        runner::{BenchStats, Statistic},
+    };
+    use std::{env, fs, process, time::Duration};
+
+    fn get_mock_report() -> PartReport {
+        PartReport {
+            part: 1,
+            answer: Some("a (b @ 5 samples)\nsecond line".into()),
+            nanos: 74.5,
+            statistic: Statistic::Median,
+            stats: BenchStats {
+                mean: Duration::from_nanos(80),
+                median: Duration::from_nanos(74),
+                min: Duration::from_nanos(70),
+                max: Duration::from_nanos(120),
+                std_dev: Duration::from_nanos(9),
+                samples: 10000,
+                outliers: 12,
+            },
+            error: None,
+            memory: Some(MemoryUsage {
+                peak_bytes: 2048,
+                allocations: 12,
+            }),
+        }
+    }
+
+    #[test]
+    fn roundtrips_reports_through_file() {
+        let path = env::temp_dir().join(format!("aoc-report-test-{}.jsonl", process::id()));
+        let _ = fs::remove_file(&path);
+
+        let first = get_mock_report();
+        let second = PartReport {
+            part: 2,
+            answer: None,
+            memory: None,
+            ..get_mock_report()
+        };
+        first.append_to(&path).unwrap();
+        second.append_to(&path).unwrap();
+
+        let reports = read_reports(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(reports, vec![first, second]);
+    }
+
+    #[test]
+    fn handles_missing_files() {
+        let path = env::temp_dir().join("aoc-report-test-missing.jsonl");
+        assert_eq!(read_reports(&path).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn rejects_malformed_reports() {
+        let json = tinyjson::JsonValue::from(&get_mock_report());
+        let mut map: std::collections::HashMap<String, tinyjson::JsonValue> =
+            json.get().cloned().unwrap();
+        map.insert("part".into(), tinyjson::JsonValue::Number(3.0));
+        assert!(PartReport::try_from(&tinyjson::JsonValue::Object(map)).is_err());
+    }
+}
@@ -1,56 +1,242 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    io, process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    registry::{self, Solution},
+    report::PartReport,
+    runner::{self, Limits, RunOptions},
+    Day, Puzzle, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
-
-    let mut need_space = false;
+/// The outcome of running a set of days.
+pub struct MultiRun {
+    /// Timings of the days, only collected for timed runs.
+    pub timings: Option<Timings>,
+    /// Whether a day or part failed, e.g. because it panicked or exceeded a limit.
+    pub has_failures: bool,
+}
 
+#[allow(clippy::too_many_arguments)]
+pub fn run_multi(
+    year: u16,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    options: RunOptions,
+    jobs: usize,
+    limits: Limits,
+    solutions: &[Solution],
+) -> MultiRun {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let puzzles: Vec<Puzzle> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+        .map(|day| Puzzle::new(year, day))
+        .collect();
+    let mut timings: Vec<Timing> = Vec::with_capacity(puzzles.len());
+    let mut failures: Vec<String> = vec![];
+
+    // in-process solutions can not be killed, enforcing limits requires child processes.
+    // this covers every registered day, so that `--timeout` also stops a day stuck in an infinite loop.
+    let solutions = if limits.is_set() { &[] } else { solutions };
+
+    let mut finish_day = |day: Day, result: Result<Vec<PartReport>, Error>| match result {
+        Ok(reports) if reports.is_empty() => println!("Not solved."),
+        Ok(reports) => {
+            for report in reports.iter().filter(|r| r.error.is_some()) {
+                let error = report.error.as_deref().unwrap_or_default();
+                failures.push(format!("Day {day} part {}: {error}", report.part));
             }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+            timings.push(child_commands::parse_exec_time(&reports, day));
+        }
+        Err(e) => {
+            println!("{e}");
+            failures.push(format!("Day {day}: {e}"));
+        }
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    // timed runs stay sequential so that concurrent days do not disturb the benchmarks.
+    if options.is_timed || jobs <= 1 {
+        for (index, &puzzle) in puzzles.iter().enumerate() {
+            print_header(puzzle.day, index > 0);
+            let result = run_day(puzzle, is_release, options, limits, solutions);
+            finish_day(puzzle.day, result);
+        }
+    } else {
+        run_parallel(
+            &puzzles,
+            jobs,
+            is_release,
+            limits,
+            solutions,
+            |index, puzzle, output, result| {
+                print_header(puzzle.day, index > 0);
+                print!("{output}");
+                finish_day(puzzle.day, result);
+            },
+        );
+    }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    if !failures.is_empty() {
+        println!("\n{ANSI_BOLD}Failed:{ANSI_RESET}");
+        for failure in &failures {
+            println!("{failure}");
+        }
+    }
 
-    if is_timed {
+    let timings = options.is_timed.then(|| {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    MultiRun {
+        timings,
+        has_failures: !failures.is_empty(),
     }
 }
 
-#[allow(dead_code)]
+fn print_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Runs a puzzle while printing its output, in-process if it is part of the registry.
+/// In-process solutions are compiled with the profile of the runner, `is_release` only applies to child processes.
+fn run_day(
+    puzzle: Puzzle,
+    is_release: bool,
+    options: RunOptions,
+    limits: Limits,
+    solutions: &[Solution],
+) -> Result<Vec<PartReport>, Error> {
+    match registry::find(solutions, puzzle) {
+        Some(solution) => solution.run_with_input(&options).map_err(Error::Solution),
+        // memory is only measured for solutions that run in-process.
+        None => child_commands::run_solution(puzzle, is_release, options, limits),
+    }
+}
+
+/// Runs a puzzle without printing, returning its output along with its reports.
+fn buffer_day(
+    puzzle: Puzzle,
+    is_release: bool,
+    limits: Limits,
+    solutions: &[Solution],
+) -> (String, Result<Vec<PartReport>, Error>) {
+    match registry::find(solutions, puzzle) {
+        Some(solution) => {
+            let options = RunOptions {
+                is_quiet: true,
+                ..RunOptions::default()
+            };
+            match solution.run_with_input(&options) {
+                Ok(reports) => (
+                    reports.iter().map(runner::format_report).collect(),
+                    Ok(reports),
+                ),
+                Err(e) => (String::new(), Err(Error::Solution(e))),
+            }
+        }
+        None => child_commands::capture_solution(puzzle, is_release, limits),
+    }
+}
+
+/// Runs puzzles on up to `jobs` threads.
+/// The output of every puzzle is buffered and handed to `on_done` in the order of `puzzles`.
+fn run_parallel(
+    puzzles: &[Puzzle],
+    jobs: usize,
+    is_release: bool,
+    limits: Limits,
+    solutions: &[Solution],
+    mut on_done: impl FnMut(usize, Puzzle, String, Result<Vec<PartReport>, Error>),
+) {
+    let next_puzzle = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let tx = tx.clone();
+            let next_puzzle = &next_puzzle;
+
+            scope.spawn(move || loop {
+                let index = next_puzzle.fetch_add(1, Ordering::Relaxed);
+                let Some(&puzzle) = puzzles.get(index) else {
+                    break;
+                };
+
+                let (output, result) = buffer_day(puzzle, is_release, limits, solutions);
+                if tx.send((index, output, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        // hold back puzzles that finished early until all puzzles before them were printed.
+        let mut finished = BTreeMap::new();
+        let mut next_to_print = 0;
+
+        for (index, output, result) in rx {
+            finished.insert(index, (output, result));
+            while let Some((output, result)) = finished.remove(&next_to_print) {
+                on_done(next_to_print, puzzles[next_to_print], output, result);
+                next_to_print += 1;
+            }
+        }
+    });
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Report(String),
+    /// An in-process solution that could not be run or panicked.
+    Solution(String),
+    /// A build that succeeded without cargo reporting the solution binary.
+    MissingBinary,
+    /// A solution binary that exited unsuccessfully, e.g. because it panicked.
+    Exited(process::ExitStatus),
+    TimedOut(Duration),
+    OutOfMemory(u64),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "Lost connection to the solution."),
+            Error::IO(e) => write!(f, "Failed to run the solution: {e}"),
+            Error::Report(e) => write!(f, "Failed to read the solution report: {e}"),
+            Error::Solution(e) => write!(f, "Failed to run the solution: {e}"),
+            Error::MissingBinary => write!(f, "Cargo did not report the solution binary."),
+            Error::Exited(status) => write!(f, "The solution failed with {status}."),
+            Error::TimedOut(timeout) => write!(f, "Timed out after {timeout:?}."),
+            Error::OutOfMemory(limit) => {
+                write!(f, "Out of memory, exceeded {} MB.", limit / 1024 / 1024)
+            }
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -59,47 +245,62 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the reports they emit.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{
+        report::{self, PartReport},
+        runner::{Limits, RunOptions, Statistic},
+        Day, Puzzle,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+        collections::HashMap,
+        env, fs,
+        io::{BufRead, BufReader, Read},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, ExitStatus, Stdio},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// Run the solution bin for a given puzzle, forwarding its output.
+    pub fn run_solution(
+        puzzle: Puzzle,
+        is_release: bool,
+        options: RunOptions,
+        limits: Limits,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&puzzle.bin_path()).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
+        // compiler errors are forwarded as is, the day counts as not solved.
+        let output = build_command(puzzle, is_release)
+            .stderr(Stdio::inherit())
+            .output()?;
+        if !output.status.success() {
+            return Ok(vec![]);
         }
-
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let bin_path = get_bin_path(&output.stdout, puzzle).ok_or(Error::MissingBinary)?;
+
+        let report_path = get_report_path(puzzle);
+        let mut args = get_args(&report_path);
+
+        if options.is_timed {
+            // mirror `--time` and `--statistic` flags to child invocations.
+            args.extend([
+                "--time".into(),
+                "--statistic".into(),
+                options.statistic.to_string(),
+            ]);
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // spawn child command with piped stdout/stderr and forward output to stdout/stderr.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(bin_path)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,150 +309,366 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let stderr_thread = thread::spawn(move || {
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                eprintln!("{line}");
+            });
+        });
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let stdout_thread = thread::spawn(move || {
+            stdout.lines().map_while(Result::ok).for_each(|line| {
+                println!("{line}");
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let status = wait_with_limits(&mut cmd, limits);
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
+
+        finish(status, &report_path)
+    }
+
+    /// Run the solution bin for a given puzzle without forwarding its output.
+    /// Returns the captured stdout and stderr along with the reports.
+    pub fn capture_solution(
+        puzzle: Puzzle,
+        is_release: bool,
+        limits: Limits,
+    ) -> (String, Result<Vec<PartReport>, Error>) {
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return (String::new(), Ok(vec![]));
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let bin_path = match build_command(puzzle, is_release).output() {
+            Ok(output) if !output.status.success() => {
+                return (String::from_utf8_lossy(&output.stderr).into(), Ok(vec![]));
+            }
+            Ok(output) => get_bin_path(&output.stdout, puzzle),
+            Err(e) => return (String::new(), Err(e.into())),
+        };
+        let Some(bin_path) = bin_path else {
+            return (String::new(), Err(Error::MissingBinary));
+        };
+
+        let report_path = get_report_path(puzzle);
+        let cmd = Command::new(bin_path)
+            .args(get_args(&report_path))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+
+        let mut cmd = match cmd {
+            Ok(cmd) => cmd,
+            Err(e) => return (String::new(), Err(e.into())),
+        };
 
-        Ok(output)
+        let (Some(stdout), Some(stderr)) = (cmd.stdout.take(), cmd.stderr.take()) else {
+            return (String::new(), Err(Error::BrokenPipe));
+        };
+
+        let stdout_thread = read_in_background(stdout);
+        let stderr_thread = read_in_background(stderr);
+
+        let status = wait_with_limits(&mut cmd, limits);
+        let captured = stdout_thread.join().unwrap() + &stderr_thread.join().unwrap();
+
+        (captured, finish(status, &report_path))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    /// Returns the answer a report holds for `part`, if any.
+    pub fn get_answer(reports: &[PartReport], part: u8) -> Option<String> {
+        reports
+            .iter()
+            .find(|r| r.part == part)
+            .and_then(|r| r.answer.clone())
+    }
+
+    /// Returns the error a report holds for `part`, if the part failed.
+    pub fn get_error(reports: &[PartReport], part: u8) -> Option<String> {
+        reports
+            .iter()
+            .find(|r| r.part == part)
+            .and_then(|r| r.error.clone())
+    }
+
+    pub fn parse_exec_time(reports: &[PartReport], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
             part_2: None,
+            part_1_nanos: None,
+            part_2_nanos: None,
             total_nanos: 0_f64,
+            statistic: Statistic::Mean,
+            part_1_memory: None,
+            part_2_memory: None,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+        // parts that are not implemented have no meaningful timing.
+        for report in reports.iter().filter(|r| r.answer.is_some()) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let nanos = report.nanos as u64;
+            let timing_str = format!("{:.1?}", Duration::from_nanos(nanos));
+
+            match report.part {
+                1 => {
+                    timings.part_1 = Some(timing_str);
+                    timings.part_1_nanos = Some(nanos);
+                    timings.part_1_memory = report.memory;
+                }
+                2 => {
+                    timings.part_2 = Some(timing_str);
+                    timings.part_2_nanos = Some(nanos);
+                    timings.part_2_memory = report.memory;
                 }
+                _ => continue,
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+            timings.total_nanos += report.nanos;
+            timings.statistic = report.statistic;
+        }
+
+        timings
+    }
+
+    /// Builds the solution bin. It is run directly afterwards so that it can be killed when exceeding limits.
+    /// Cargo prints the built artifacts as JSON to stdout, see [`get_bin_path`], and compiler errors to stderr.
+    fn build_command(puzzle: Puzzle, is_release: bool) -> Command {
+        let mut cmd = Command::new("cargo");
+        cmd.args([
+            "build",
+            "--quiet",
+            "--message-format=json-render-diagnostics",
+            "--bin",
+            &puzzle.to_string(),
+        ]);
+
+        if is_release {
+            cmd.arg("--release");
+        }
+
+        cmd
+    }
+
+    /// Returns the path of the solution bin from the messages of [`build_command`].
+    /// Cargo knows where it put the bin, whatever target directory, profile or target is configured.
+    fn get_bin_path(messages: &[u8], puzzle: Puzzle) -> Option<PathBuf> {
+        let name = puzzle.to_string();
+
+        String::from_utf8_lossy(messages).lines().find_map(|line| {
+            let message: JsonValue = line.parse().ok()?;
+            let message = message.get::<HashMap<String, JsonValue>>()?;
+            let target = message.get("target")?.get::<HashMap<String, JsonValue>>()?;
+
+            let is_bin = message.get("reason")?.get::<String>()? == "compiler-artifact"
+                && target.get("name")?.get::<String>()? == &name;
+            let executable = message.get("executable")?.get::<String>()?;
+
+            is_bin.then(|| PathBuf::from(executable))
+        })
+    }
+
+    fn get_args(report_path: &Path) -> Vec<String> {
+        vec!["--report".into(), report_path.to_string_lossy().to_string()]
+    }
+
+    fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+        thread::spawn(move || {
+            let mut captured = String::new();
+            let _ = pipe.read_to_string(&mut captured);
+            captured
+        })
+    }
+
+    /// Waits for the child to exit, killing it once it exceeds one of the limits.
+    /// Fails if the child did not exit successfully, e.g. because it panicked.
+    fn wait_with_limits(child: &mut Child, limits: Limits) -> Result<(), Error> {
+        if !limits.is_set() {
+            return check_status(child.wait()?);
+        }
+
+        let start = Instant::now();
+
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return check_status(status);
+            }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            let breach = match (limits.timeout, limits.memory) {
+                (Some(timeout), _) if start.elapsed() > timeout => Some(Error::TimedOut(timeout)),
+                (_, Some(memory)) if resident_memory(child.id()).is_some_and(|m| m > memory) => {
+                    Some(Error::OutOfMemory(memory))
                 }
+                _ => None,
+            };
 
-                timings.total_nanos += nanos;
-            });
+            if let Some(e) = breach {
+                child.kill()?;
+                child.wait()?;
+                return Err(e);
+            }
 
-        timings
+            thread::sleep(Duration::from_millis(10));
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Resident memory of a process in bytes. Only available on Linux.
+    fn resident_memory(pid: u32) -> Option<u64> {
+        let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+        let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+        let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kilobytes * 1024)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn finish(status: Result<(), Error>, report_path: &Path) -> Result<Vec<PartReport>, Error> {
+        match status {
+            Ok(()) => collect_reports(report_path),
+            Err(e) => {
+                // drop what was reported before the child was killed.
+                let _ = fs::remove_file(report_path);
+                Err(e)
+            }
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Fails if the solution did not exit successfully, e.g. because it panicked.
+    fn check_status(status: ExitStatus) -> Result<(), Error> {
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::Exited(status))
+        }
+    }
+
+    fn get_report_path(puzzle: Puzzle) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-report-{}-{puzzle}.jsonl", process::id()));
+        // a stale report would be mixed with the fresh one.
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn collect_reports(path: &Path) -> Result<Vec<PartReport>, Error> {
+        let reports = report::read_reports(path);
+        let _ = fs::remove_file(path);
+        reports.map_err(Error::Report)
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use super::{
+            get_answer, get_bin_path, get_error, parse_exec_time, resident_memory, wait_with_limits,
+        };
+        use crate::{
+            day,
+            template::{
+                report::PartReport,
+                run_multi::Error,
+                runner::{BenchStats, Limits, Statistic},
+                Puzzle,
+            },
+        };
+        use std::{path::PathBuf, process, time::Duration};
+
+        fn report(part: u8, answer: Option<&str>, nanos: f64) -> PartReport {
+            PartReport {
+                part,
+                answer: answer.map(String::from),
+                nanos,
+                statistic: Statistic::Min,
+                stats: BenchStats::single(Duration::from_nanos(nanos as u64)),
+                error: None,
+                memory: None,
+            }
+        }
 
         #[test]
         fn parses_execution_times() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report(1, Some("0"), 74.0),
+                    report(2, Some("10"), 74_130_000.0),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.total_nanos, 74_130_074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_2_nanos, Some(74_130_000));
+            assert_eq!(res.statistic, Statistic::Min);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_missing_parts() {
+            let res = parse_exec_time(&[report(1, None, 10.0), report(2, None, 10.0)], day!(1));
+            assert_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1.is_none(), true);
+            assert_eq!(res.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn reads_bin_paths_from_build_messages() {
+            let messages = concat!(
+                r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code"},"executable":null}"#,
+                "\n",
+                r#"{"reason":"compiler-artifact","target":{"name":"2024-01"},"executable":"/tmp/target/release/2024-01"}"#,
+                "\n",
+                r#"{"reason":"build-finished","success":true}"#,
+            );
+            assert_eq!(
+                get_bin_path(messages.as_bytes(), Puzzle::new(2024, day!(1))),
+                Some(PathBuf::from("/tmp/target/release/2024-01"))
+            );
+            assert_eq!(
+                get_bin_path(messages.as_bytes(), Puzzle::new(2024, day!(2))),
+                None
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn gets_answers() {
+            let reports = [
+                report(1, Some("1 (2 @ 3 samples)"), 1.0),
+                report(2, None, 1.0),
+            ];
+            assert_eq!(get_answer(&reports, 1), Some("1 (2 @ 3 samples)".into()));
+            assert_eq!(get_answer(&reports, 2), None);
+
+            let failed = PartReport {
+                error: Some("invalid digit found in string".into()),
+                ..report(2, None, 1.0)
+            };
+            assert_eq!(
+                get_error(&[failed], 2),
+                Some("invalid digit found in string".into())
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(get_error(&reports, 1), None);
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn kills_children_exceeding_timeout() {
+            let mut child = process::Command::new("sleep").arg("10").spawn().unwrap();
+            let limits = Limits {
+                timeout: Some(Duration::from_millis(50)),
+                memory: None,
+            };
+            let res = wait_with_limits(&mut child, limits);
+            assert!(matches!(res, Err(Error::TimedOut(_))));
+            assert!(child.try_wait().unwrap().is_some());
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn fails_for_unsuccessful_children() {
+            let mut child = process::Command::new("false").spawn().unwrap();
+            let res = wait_with_limits(&mut child, Limits::default());
+            assert!(matches!(res, Err(Error::Exited(_))));
+        }
+
+        #[test]
+        #[cfg(target_os = "linux")]
+        fn reads_resident_memory() {
+            assert!(resident_memory(process::id()).is_some_and(|m| m > 0));
         }
     }
 }
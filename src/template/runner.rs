@@ -2,63 +2,425 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::path::Path;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::alloc::{self, MemoryUsage};
+use crate::template::aoc_client::{self, AocClientError};
+use crate::template::ledger::Ledger;
+use crate::template::ocr;
+use crate::template::report::PartReport;
+use crate::template::submission::SubmissionOutcome;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Puzzle, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Options that control how a solution part is run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Bench the part instead of running it once.
+    pub is_timed: bool,
+    pub statistic: Statistic,
+    /// Do not print results.
+    pub is_quiet: bool,
+    /// Measure the heap usage of the first execution, see [`alloc::measure`].
+    pub measure_memory: bool,
+}
+
+impl RunOptions {
+    /// Reads the options passed to a solution binary.
+    pub fn from_args() -> Self {
+        Self {
+            is_timed: env::args().any(|x| x == "--time"),
+            statistic: get_arg_value("--statistic")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or_default(),
+            is_quiet: false,
+            measure_memory: false,
+        }
+    }
+}
+
+/// Resource limits for a solution that runs as a child process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    /// Wall-clock time after which the solution is killed.
+    pub timeout: Option<Duration>,
+    /// Resident memory in bytes after which the solution is killed. Only enforced on Linux.
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.memory.is_some()
+    }
+}
+
+/// Return types of solution parts, implemented for [`Option`] and [`Result`].
+/// [`None`] marks a part that is not implemented yet, an [`Err`] a part that failed, e.g. on malformed input.
+pub trait PartResult {
+    /// The answer as text, or the error message if the part failed.
+    fn answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Runs and prints one part of a solution binary, submitting its result if requested.
+/// Returns the outcome of the submission, if one was made.
+pub fn run_part<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+) -> Option<Result<SubmissionOutcome, AocClientError>> {
+    let report = run_part_with(func, input, part, &RunOptions::from_args());
+
+    if let Some(path) = get_arg_value("--report") {
+        if let Err(e) = report.append_to(Path::new(&path)) {
+            eprintln!("Failed to write report: {e}");
+        }
+    }
+
+    submit_result(report.answer?, puzzle, part)
+}
+
+/// Runs and prints one part of a solution and reports its answer and timing.
+pub fn run_part_with<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport {
+    run_labelled(func, input, part, &format!("Part {part}"), options)
+}
+
+/// A named implementation of a solution part, see [`compare_variants`].
+pub struct Variant<I, R> {
+    pub name: &'static str,
+    pub func: fn(I) -> R,
+}
+
+impl<I, R> Variant<I, R> {
+    pub fn new(name: &'static str, func: fn(I) -> R) -> Self {
+        Self { name, func }
+    }
+}
+
+/// Runs every variant of a part and prints a comparison of their answers and timings.
+/// Returns whether all variants agree on the answer.
+pub fn compare_variants<I: Copy, R: PartResult>(
+    variants: &[Variant<I, R>],
+    input: I,
+    part: u8,
+    options: &RunOptions,
+) -> bool {
+    let reports: Vec<(&str, PartReport)> = variants
+        .iter()
+        .map(|variant| {
+            let label = format!("Part {part} ({})", variant.name);
+            let report = run_labelled(variant.func, input, part, &label, options);
+            (variant.name, report)
+        })
+        .collect();
+
+    println!("{}", format_variant_comparison(part, &reports));
+
+    let is_agreeing = variants_agree(&reports);
+    if !is_agreeing {
+        eprintln!("Variants of part {part} do not agree on the answer.");
+    }
+    is_agreeing
+}
+
+/// Variants agree if none of them failed and all of them found the same answer.
+fn variants_agree(reports: &[(&str, PartReport)]) -> bool {
+    reports.iter().all(|(_, report)| report.error.is_none())
+        && reports.windows(2).all(|w| w[0].1.answer == w[1].1.answer)
+}
+
+/// Formats a table of variants with their timing relative to the fastest variant.
+fn format_variant_comparison(part: u8, reports: &[(&str, PartReport)]) -> String {
+    let fastest = reports
+        .iter()
+        .map(|(_, report)| report.nanos)
+        .fold(f64::INFINITY, f64::min);
+
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Part {part} variants{ANSI_RESET}"),
+        "| Variant | Answer | Time | Relative |".into(),
+    ];
+
+    for (name, report) in reports {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = Duration::from_nanos(report.nanos as u64);
+        let relative = if report.nanos <= fastest {
+            "fastest".into()
+        } else {
+            format!("{:.1}x", report.nanos / fastest.max(1.0))
+        };
+
+        lines.push(format!(
+            "| {name} | {} | {duration:.1?} | {relative} |",
+            report.answer.as_deref().unwrap_or("✖")
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn run_labelled<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    part: u8,
+    part_str: &str,
+    options: &RunOptions,
+) -> PartReport {
+    let print = |answer: &Result<Option<String>, String>, duration_str: &str| {
+        if !options.is_quiet {
+            print_result(answer, part_str, duration_str);
+        }
+    };
+
+    let (answer, stats, memory) = run_timed(func, input, options, |answer| print(answer, ""));
+
+    let memory_str = memory.map(format_memory).unwrap_or_default();
+    print(
+        &answer,
+        &format!("{}{memory_str}", format_duration(&stats, options.statistic)),
+    );
+
+    // letter art is reported as the letters it draws, so that it can be submitted and verified.
+    let (answer, error) = match answer {
+        Ok(answer) => (answer.map(|a| ocr::read_letters(&a).unwrap_or(a)), None),
+        Err(e) => (None, Some(e)),
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    PartReport {
+        part,
+        answer,
+        nanos: stats.get(options.statistic).as_nanos() as f64,
+        statistic: options.statistic,
+        stats,
+        error,
+        memory,
+    }
+}
+
+/// Formats a report the same way a part is printed after running, for output that is buffered.
+pub fn format_report(report: &PartReport) -> String {
+    let memory_str = report.memory.map(format_memory).unwrap_or_default();
+    let duration_str = format!(
+        "{}{memory_str}",
+        format_duration(&report.stats, report.statistic)
+    );
+    let part = report.part;
+
+    if let Some(e) = &report.error {
+        return format!("Part {part}: ✖ {e}\n");
+    }
+
+    match &report.answer {
+        Some(answer) if answer.contains('\n') => {
+            format!("Part {part}: ▼ {duration_str}\n{answer}\n")
+        }
+        Some(answer) => format!("Part {part}: {ANSI_BOLD}{answer}{ANSI_RESET}{duration_str}\n"),
+        None => format!("Part {part}: ✖\n"),
+    }
+}
+
+/// Maps the result of a submission to the exit code of the solution binary.
+pub fn submission_exit_code(submission: &Result<SubmissionOutcome, AocClientError>) -> i32 {
+    match submission {
+        Ok(outcome) => outcome.exit_code(),
+        Err(_) => 1,
+    }
+}
+
+/// The statistic that is reported as the headline timing of a benchmark.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Statistic {
+    Mean,
+    #[default]
+    Median,
+    Min,
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Statistic::Mean => write!(f, "mean"),
+            Statistic::Median => write!(f, "median"),
+            Statistic::Min => write!(f, "min"),
+        }
+    }
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+impl FromStr for Statistic {
+    type Err = String;
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Statistic::Mean),
+            "median" => Ok(Statistic::Median),
+            "min" => Ok(Statistic::Min),
+            _ => Err(format!(
+                "unknown statistic `{s}`, expecting one of mean, median, min"
+            )),
+        }
+    }
+}
+
+/// Summary of the samples collected while benching a solution part.
+/// Statistics are computed after rejecting outliers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub samples: u128,
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Stats for a solution part that was executed once.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            mean: duration,
+            median: duration,
+            min: duration,
+            max: duration,
+            std_dev: Duration::ZERO,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+
+    /// Computes stats from raw samples, dropping samples outside of Tukey's fences (1.5 IQR).
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let total = samples.len();
+
+        let quartile = |q: usize| samples[(total - 1) * q / 4];
+        let (q1, q3) = (quartile(1), quartile(3));
+        let iqr = q3.saturating_sub(q1);
+        let lower = q1.saturating_sub(iqr * 3 / 2);
+        let upper = q3 + iqr * 3 / 2;
+
+        let kept: Vec<Duration> = samples
+            .iter()
+            .copied()
+            .filter(|d| (lower..=upper).contains(d))
+            .collect();
+
+        let nanos: Vec<f64> = kept.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Self {
+            mean: Duration::from_nanos(mean as u64),
+            median: kept[kept.len() / 2],
+            min: kept[0],
+            max: kept[kept.len() - 1],
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            samples: total as u128,
+            outliers: total - kept.len(),
+        }
+    }
+
+    pub fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Mean => self.mean,
+            Statistic::Median => self.median,
+            Statistic::Min => self.min,
+        }
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
+///
+/// Parts that fail are not benched.
+fn run_timed<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
-
-        func(input)
+    options: &RunOptions,
+    hook: impl Fn(&Result<Option<String>, String>),
+) -> (
+    Result<Option<String>, String>,
+    BenchStats,
+    Option<MemoryUsage>,
+) {
+    let run_once = || {
+        let timer = Instant::now();
+        let result = {
+            #[cfg(feature = "dhat-heap")]
+            let _profiler = dhat::Profiler::new_heap();
+
+            func(input)
+        };
+        (result, timer.elapsed())
+    };
+
+    let ((result, base_time), memory) = if options.measure_memory {
+        alloc::measure(run_once)
+    } else {
+        (run_once(), None)
     };
-    let base_time = timer.elapsed();
 
-    hook(&result);
+    let answer = result.answer();
+    hook(&answer);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.is_timed && answer.is_ok() {
+        bench(func, input, &base_time, options.is_quiet)
     } else {
-        (base_time, 1)
+        BenchStats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (answer, stats, memory)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    is_quiet: bool,
+) -> BenchStats {
+    if !is_quiet {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    // warm up caches and branch predictors before collecting samples.
+    for _ in 0..(bench_iterations / 10) {
+        black_box(func(black_box(input)));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -67,36 +429,48 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Reads the value following `name` from the command-line arguments.
+fn get_arg_value(name: &str) -> Option<String> {
+    let mut args = env::args().skip_while(|x| x != name);
+    args.next()?;
+    args.next()
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
+fn format_duration(stats: &BenchStats, statistic: Statistic) -> String {
+    let duration = stats.get(statistic);
+    if stats.samples == 1 {
         format!(" ({duration:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({duration:.1?} @ {} samples; {statistic}, σ {:.1?}, min {:.1?}, max {:.1?}, {} outliers)",
+            stats.samples, stats.std_dev, stats.min, stats.max, stats.outliers
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_memory(memory: MemoryUsage) -> String {
+    format!(
+        " ({} peak, {} allocations)",
+        alloc::format_bytes(memory.peak_bytes),
+        memory.allocations
+    )
+}
+
+fn print_result(answer: &Result<Option<String>, String>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+    match answer {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
+                let str = match ocr::read_letters(result) {
+                    Some(letters) => {
+                        format!("{part}: {ANSI_BOLD}{letters}{ANSI_RESET} ▼{duration_str}")
+                    }
+                    None => format!("{part}: ▼ {duration_str}"),
+                };
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -114,7 +488,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,17 +496,24 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(e) => {
+            if !is_intermediate_result {
+                print!("\r");
+                println!("{part}: ✖ {e}");
+            }
+        }
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///  3. the ledger of previous submissions does not rule the answer out.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: Puzzle,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<SubmissionOutcome, AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,11 +536,209 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    if let Err(e) = aoc_client::check() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    let answer = result.to_string();
+    let mut ledger = match Ledger::read_from_file(puzzle.year) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Refusing to submit result, failed to read submission ledger: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(rejection) = ledger.check(puzzle.day, part, &answer) {
+        eprintln!("Refusing to submit result: {rejection}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let submission = aoc_client::submit(puzzle, part, &answer);
+    match &submission {
+        Ok(outcome) => {
+            println!("{ANSI_BOLD}Part {part}:{ANSI_RESET} {outcome}");
+            ledger.record(puzzle.day, part, &answer, outcome);
+            // the website does not check answers of solved parts, remember the accepted one instead.
+            if *outcome == SubmissionOutcome::AlreadyCompleted {
+                match aoc_client::solved_answer(puzzle, part) {
+                    Ok(Some(correct)) => {
+                        println!("The accepted answer is {correct}.");
+                        ledger.record(puzzle.day, part, &correct, &SubmissionOutcome::Correct);
+                    }
+                    Ok(None) => {}
+                    Err(e) => eprintln!("failed to fetch the accepted answer: {e}"),
+                }
+            }
+            if ledger.store_file(puzzle.year).is_err() {
+                eprintln!("Failed to store submission ledger.");
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
+    }
+    Some(submission)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        format_duration, format_memory, format_report, format_variant_comparison, variants_agree,
+        BenchStats, PartResult, Statistic,
+    };
+    use crate::template::{alloc::MemoryUsage, report::PartReport, ANSI_BOLD, ANSI_RESET};
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = BenchStats::from_samples(nanos(&[30, 10, 20, 40, 50]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.std_dev, Duration::from_nanos(14));
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(nanos(&[10, 11, 10, 12, 11, 10, 5000]));
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(stats.get(Statistic::Median), Duration::from_nanos(11));
+        assert_eq!(stats.get(Statistic::Min), Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn formats_durations() {
+        let stats = BenchStats::from_samples(nanos(&[10, 20, 30]));
+        assert_eq!(
+            format_duration(&stats, Statistic::Median),
+            " (20.0ns @ 3 samples; median, σ 8.0ns, min 10.0ns, max 30.0ns, 0 outliers)"
+        );
+        assert_eq!(
+            format_duration(
+                &BenchStats::single(Duration::from_nanos(5)),
+                Statistic::Mean
+            ),
+            " (5.0ns)"
+        );
+    }
+
+    #[test]
+    fn formats_reports() {
+        let report = PartReport {
+            part: 2,
+            answer: Some("42".into()),
+            nanos: 1500.0,
+            statistic: Statistic::Median,
+            stats: BenchStats::single(Duration::from_nanos(1500)),
+            error: None,
+            memory: None,
+        };
+        assert_eq!(
+            format_report(&report),
+            format!("Part 2: {ANSI_BOLD}42{ANSI_RESET} (1.5µs)\n")
+        );
+
+        // buffered output shows the same statistics as output printed while running.
+        let stats = BenchStats::from_samples(nanos(&[10, 20, 30]));
+        let benched = PartReport {
+            nanos: 20.0,
+            stats: stats.clone(),
+            memory: Some(MemoryUsage {
+                peak_bytes: 1536,
+                allocations: 3,
+            }),
+            ..report.clone()
+        };
+        assert_eq!(
+            format_report(&benched),
+            format!(
+                "Part 2: {ANSI_BOLD}42{ANSI_RESET}{}{}\n",
+                format_duration(&stats, Statistic::Median),
+                format_memory(benched.memory.unwrap())
+            )
+        );
+
+        let report = PartReport {
+            answer: None,
+            ..report
+        };
+        assert_eq!(format_report(&report), "Part 2: ✖\n");
+
+        let report = PartReport {
+            error: Some("invalid digit found in string".into()),
+            ..report
+        };
+        assert_eq!(
+            format_report(&report),
+            "Part 2: ✖ invalid digit found in string\n"
+        );
+    }
+
+    #[test]
+    fn converts_part_results() {
+        assert_eq!(Some(42).answer(), Ok(Some("42".into())));
+        assert_eq!(None::<u64>.answer(), Ok(None));
+        assert_eq!(Ok::<u64, String>(42).answer(), Ok(Some("42".into())));
+        assert_eq!(
+            "x".parse::<u64>().answer(),
+            Err("invalid digit found in string".into())
+        );
+    }
+
+    #[test]
+    fn compares_variants() {
+        let report = |answer: &str, nanos: f64| PartReport {
+            part: 2,
+            answer: Some(answer.into()),
+            nanos,
+            statistic: Statistic::Median,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            stats: BenchStats::single(Duration::from_nanos(nanos as u64)),
+            error: None,
+            memory: None,
+        };
+        let reports = [
+            ("part_two", report("6", 2000.0)),
+            ("part_two_brute_force", report("6", 500_000.0)),
+        ];
+
+        assert!(variants_agree(&reports));
+        assert_eq!(
+            format_variant_comparison(2, &reports),
+            [
+                format!("{ANSI_BOLD}Part 2 variants{ANSI_RESET}"),
+                "| Variant | Answer | Time | Relative |".into(),
+                "| part_two | 6 | 2.0µs | fastest |".into(),
+                "| part_two_brute_force | 6 | 500.0µs | 250.0x |".into(),
+            ]
+            .join("\n")
+        );
+
+        assert!(!variants_agree(&[
+            ("part_two", report("6", 2000.0)),
+            ("part_two_fast", report("7", 1000.0)),
+        ]));
+
+        let failed = |e: &str| PartReport {
+            answer: None,
+            error: Some(e.into()),
+            ..report("", 1000.0)
+        };
+        assert!(!variants_agree(&[
+            ("part_two", failed("no path")),
+            ("part_two_fast", failed("no path")),
+        ]));
+    }
 }
@@ -0,0 +1,182 @@
+/// Interprets the response of the Advent of Code website to a submitted answer.
+use std::{fmt::Display, time::Duration};
+
+/// The result of submitting an answer for one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    /// The answer is wrong and the site hinted that it is too high.
+    TooHigh,
+    /// The answer is wrong and the site hinted that it is too low.
+    TooLow,
+    /// The answer is wrong and the site gave no hint.
+    Incorrect,
+    /// The part was already solved, no answer was checked.
+    AlreadyCompleted,
+    /// An answer was submitted too recently, no answer was checked.
+    RateLimited(Option<Duration>),
+    /// The response did not match any known message.
+    Unknown(String),
+}
+
+impl SubmissionOutcome {
+    /// Parses the message the website returns after posting an answer.
+    pub fn from_response(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Self::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Self::TooHigh
+            } else if message.contains("too low") {
+                Self::TooLow
+            } else {
+                Self::Incorrect
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Self::AlreadyCompleted
+        } else if message.contains("You gave an answer too recently") {
+            Self::RateLimited(parse_wait_time(message))
+        } else {
+            Self::Unknown(message.trim().to_string())
+        }
+    }
+
+    /// Whether the website actually checked the answer.
+    pub fn is_checked(&self) -> bool {
+        matches!(
+            self,
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Incorrect
+        )
+    }
+
+    /// Process exit code used by solution binaries after submitting, so scripts can react.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Correct => 0,
+            Self::TooHigh | Self::TooLow | Self::Incorrect => 2,
+            Self::AlreadyCompleted => 3,
+            Self::RateLimited(_) => 4,
+            Self::Unknown(_) => 5,
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "⭐ correct answer!"),
+            Self::TooHigh => write!(f, "✖ wrong answer, too high."),
+            Self::TooLow => write!(f, "✖ wrong answer, too low."),
+            Self::Incorrect => write!(f, "✖ wrong answer."),
+            Self::AlreadyCompleted => write!(f, "this part is already completed."),
+            Self::RateLimited(Some(wait)) => {
+                write!(
+                    f,
+                    "rate limited, wait {}s before submitting again.",
+                    wait.as_secs()
+                )
+            }
+            Self::RateLimited(None) => write!(f, "rate limited, wait before submitting again."),
+            Self::Unknown(message) => write!(f, "unrecognized response:\n{message}"),
+        }
+    }
+}
+
+/// Reads the accepted answers from the page of a puzzle, one for every solved part in order.
+/// The website shows them as `Your puzzle answer was <code>42</code>.` below each part.
+pub fn solved_answers(page: &str) -> Vec<String> {
+    page.split("Your puzzle answer was <code>")
+        .skip(1)
+        .filter_map(|rest| Some(rest.split_once("</code>")?.0.to_string()))
+        .collect()
+}
+
+/// Parses wait times like `You have 1m 20s left to wait.`
+fn parse_wait_time(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in wait.split_whitespace() {
+        let (value, multiplier) = if let Some(v) = token.strip_suffix('h') {
+            (v, 3600)
+        } else if let Some(v) = token.strip_suffix('m') {
+            (v, 60)
+        } else {
+            (token.strip_suffix('s')?, 1)
+        };
+        seconds += value.parse::<u64>().ok()? * multiplier;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{solved_answers, SubmissionOutcome};
+    use std::time::Duration;
+
+    #[test]
+    fn parses_correct_answers() {
+        let outcome = SubmissionOutcome::from_response(
+            "That's the right answer! You are one gold star closer to finding the Chief Historian.",
+        );
+        assert_eq!(outcome, SubmissionOutcome::Correct);
+    }
+
+    #[test]
+    fn parses_wrong_answers() {
+        let outcome = SubmissionOutcome::from_response(
+            "That's not the right answer; your answer is too high. Please wait one minute before trying again.",
+        );
+        assert_eq!(outcome, SubmissionOutcome::TooHigh);
+
+        let outcome = SubmissionOutcome::from_response(
+            "That's not the right answer; your answer is too low.",
+        );
+        assert_eq!(outcome, SubmissionOutcome::TooLow);
+
+        let outcome = SubmissionOutcome::from_response(
+            "That's not the right answer. If you're stuck, make sure you're using the full input data.",
+        );
+        assert_eq!(outcome, SubmissionOutcome::Incorrect);
+    }
+
+    #[test]
+    fn parses_completed_parts() {
+        let outcome = SubmissionOutcome::from_response(
+            "You don't seem to be solving the right level.  Did you already complete it?",
+        );
+        assert_eq!(outcome, SubmissionOutcome::AlreadyCompleted);
+        assert!(!outcome.is_checked());
+    }
+
+    #[test]
+    fn reads_solved_answers() {
+        let page = "<article><p>Part one.</p></article><p>Your puzzle answer was <code>1234</code>.</p><article><p>Part two.</p></article><p>Your puzzle answer was <code>ABC</code>.</p>";
+        assert_eq!(solved_answers(page), vec!["1234", "ABC"]);
+        assert!(solved_answers("<article><p>Part one.</p></article>").is_empty());
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        let outcome = SubmissionOutcome::from_response(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 20s left to wait.",
+        );
+        assert_eq!(
+            outcome,
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(80)))
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_messages() {
+        let outcome = SubmissionOutcome::from_response(" Something else. ");
+        assert_eq!(
+            outcome,
+            SubmissionOutcome::Unknown("Something else.".into())
+        );
+    }
+}
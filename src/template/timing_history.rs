@@ -0,0 +1,319 @@
+/// Append-only log of stored benchmark runs, used to follow how a day's runtime evolved.
+/// Every line of the log is one JSON object describing one day of one run.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    runner::Statistic,
+    timings::{Timing, Timings},
+    year_data_dir, Day, Puzzle,
+};
+
+fn get_file_path(year: u16) -> String {
+    format!("./{}/timings_history.jsonl", year_data_dir(year))
+}
+
+/// Where and when a benchmark run happened.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunContext {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the `HEAD` commit, suffixed with `-dirty` for uncommitted changes.
+    /// Changes below `data/`, e.g. stored timings and inputs, do not count.
+    pub commit: Option<String>,
+    pub rustc: Option<String>,
+}
+
+impl RunContext {
+    pub fn current() -> Self {
+        let commit = run_command("git", &["rev-parse", "--short", "HEAD"]).map(|commit| {
+            let is_dirty = run_command(
+                "git",
+                &["status", "--porcelain", "--", ".", ":(exclude)data"],
+            )
+            .is_some_and(|status| !status.is_empty());
+            if is_dirty {
+                format!("{commit}-dirty")
+            } else {
+                commit
+            }
+        });
+
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            commit,
+            // e.g. `rustc 1.83.0 (90b35a623 2024-11-26)`.
+            rustc: run_command("rustc", &["--version"])
+                .and_then(|v| v.split_whitespace().nth(1).map(String::from)),
+        }
+    }
+}
+
+/// The timing of one day in one benchmark run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub context: RunContext,
+    pub day: Day,
+    pub part_1_nanos: Option<u64>,
+    pub part_2_nanos: Option<u64>,
+    pub statistic: Statistic,
+}
+
+impl HistoryEntry {
+    pub fn new(timing: &Timing, context: &RunContext) -> Self {
+        Self {
+            context: context.clone(),
+            day: timing.day,
+            part_1_nanos: timing.part_nanos(1),
+            part_2_nanos: timing.part_nanos(2),
+            statistic: timing.statistic,
+        }
+    }
+
+    pub fn total_nanos(&self) -> u64 {
+        self.part_1_nanos.unwrap_or_default() + self.part_2_nanos.unwrap_or_default()
+    }
+}
+
+/// Appends one entry per day of `timings` to the history file of `year`.
+pub fn append(year: u16, timings: &Timings, context: &RunContext) -> Result<(), io::Error> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_file_path(year))?;
+
+    for timing in &timings.data {
+        let line = JsonValue::from(&HistoryEntry::new(timing, context))
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Reads all entries of a puzzle in the order they were recorded. If not present, returns no entries.
+pub fn read_puzzle(puzzle: Puzzle) -> Result<Vec<HistoryEntry>, String> {
+    let Ok(content) = fs::read_to_string(get_file_path(puzzle.year)) else {
+        return Ok(vec![]);
+    };
+
+    let entries = parse(&content)?;
+    Ok(entries
+        .into_iter()
+        .filter(|e| e.day == puzzle.day)
+        .collect())
+}
+
+fn parse(content: &str) -> Result<Vec<HistoryEntry>, String> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).map_err(|_| "not a valid JSON line.".to_string())?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+fn run_command(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Formats a unix timestamp as `YYYY-MM-DD HH:MM` in UTC.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86_400, timestamp % 86_400);
+
+    // civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    #[allow(clippy::cast_possible_wrap)]
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn optional<T: Clone>(value: Option<&T>, map: impl Fn(T) -> JsonValue) -> JsonValue {
+    value.cloned().map_or(JsonValue::Null, map)
+}
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.context.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            optional(value.context.commit.as_ref(), JsonValue::String),
+        );
+        map.insert(
+            "rustc".into(),
+            optional(value.context.rustc.as_ref(), JsonValue::String),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part_1_nanos".into(),
+            optional(value.part_1_nanos.as_ref(), |n| JsonValue::Number(n as f64)),
+        );
+        map.insert(
+            "part_2_nanos".into(),
+            optional(value.part_2_nanos.as_ref(), |n| JsonValue::Number(n as f64)),
+        );
+        map.insert(
+            "statistic".into(),
+            JsonValue::String(value.statistic.to_string()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let optional_string = |key: &str| -> Result<Option<String>, String> {
+            match json.get(key) {
+                Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+                Some(JsonValue::Null) | None => Ok(None),
+                Some(_) => Err(format!("Expected history.{key} to be null or string.")),
+            }
+        };
+
+        let optional_number = |key: &str| -> Result<Option<u64>, String> {
+            match json.get(key) {
+                Some(JsonValue::Number(n)) => Ok(Some(*n as u64)),
+                Some(JsonValue::Null) | None => Ok(None),
+                Some(_) => Err(format!("Expected history.{key} to be null or number.")),
+            }
+        };
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected history.timestamp to be a number.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected history.day to be a Day struct.")?;
+
+        let statistic = json
+            .get("statistic")
+            .and_then(|v| v.get::<String>())
+            .and_then(|s| s.parse().ok())
+            .ok_or("Expected history.statistic to be a known statistic.")?;
+
+        Ok(HistoryEntry {
+            context: RunContext {
+                timestamp: *timestamp as u64,
+                commit: optional_string("commit")?,
+                rustc: optional_string("rustc")?,
+            },
+            day,
+            part_1_nanos: optional_number("part_1_nanos")?,
+            part_2_nanos: optional_number("part_2_nanos")?,
+            statistic,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_timestamp, parse, HistoryEntry, RunContext};
+    use crate::{
+        day,
+        template::{runner::Statistic, timings::Timing},
+    };
+
+    fn get_mock_context() -> RunContext {
+        RunContext {
+            timestamp: 1_733_400_000,
+            commit: Some("abc1234-dirty".into()),
+            rustc: None,
+        }
+    }
+
+    #[test]
+    fn creates_entries_from_timings() {
+        let timing = Timing {
+            day: day!(5),
+            part_1: Some("1.5ms".into()),
+            part_2: None,
+            part_1_nanos: Some(1_500_042),
+            part_2_nanos: None,
+            total_nanos: 1_500_000.0,
+            statistic: Statistic::Median,
+            part_1_memory: None,
+            part_2_memory: None,
+        };
+        let entry = HistoryEntry::new(&timing, &get_mock_context());
+        assert_eq!(entry.part_1_nanos, Some(1_500_042));
+        assert_eq!(entry.part_2_nanos, None);
+        assert_eq!(entry.total_nanos(), 1_500_042);
+    }
+
+    #[test]
+    fn roundtrips_json_lines() {
+        let entry = HistoryEntry {
+            context: get_mock_context(),
+            day: day!(5),
+            part_1_nanos: Some(100),
+            part_2_nanos: Some(200),
+            statistic: Statistic::Min,
+        };
+        let line = tinyjson::JsonValue::from(&entry).stringify().unwrap();
+        let parsed = parse(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(parsed, vec![entry.clone(), entry]);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(parse(r#"{ "day": "05" }"#).is_err());
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_400_000), "2024-12-05 12:00");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+    }
+}
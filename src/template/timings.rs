@@ -1,20 +1,75 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{alloc::MemoryUsage, runner::Statistic, year_data_dir, Day};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+fn get_file_path(year: u16) -> String {
+    format!("./{}/timings.json", year_data_dir(year))
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Formatted execution times of the parts, as shown in the readme.
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Exact execution times of the parts, used to compare runs.
+    /// Not present for timings that were stored before they were recorded.
+    pub part_1_nanos: Option<u64>,
+    pub part_2_nanos: Option<u64>,
     pub total_nanos: f64,
+    /// The statistic that `part_1` and `part_2` report.
+    pub statistic: Statistic,
+    /// Heap usage of a part, if it was measured with `cargo time --memory`.
+    pub part_1_memory: Option<MemoryUsage>,
+    pub part_2_memory: Option<MemoryUsage>,
+}
+
+impl Timing {
+    /// Execution time of a part in nanoseconds.
+    pub fn part_nanos(&self, part: u8) -> Option<u64> {
+        match part {
+            1 => self.part_1_nanos,
+            2 => self.part_2_nanos,
+            _ => None,
+        }
+    }
+
+    pub fn part_memory(&self, part: u8) -> Option<MemoryUsage> {
+        match part {
+            1 => self.part_1_memory,
+            2 => self.part_2_memory,
+            _ => None,
+        }
+    }
+}
+
+/// The execution time of a part compared with a baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: u64,
+    pub current_nanos: u64,
+    /// Whether baseline and current timing report the same statistic.
+    pub is_comparable: bool,
 }
 
-/// Represents benchmark times for a set of days.
+impl PartDelta {
+    /// Relative change in percent, positive if the part got slower.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn change_percent(&self) -> f64 {
+        let (baseline, current) = (self.baseline_nanos as f64, self.current_nanos as f64);
+        (current - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.is_comparable && self.change_percent() > threshold_percent
+    }
+}
+
+/// Represents benchmark times for a set of days of one year.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
@@ -22,16 +77,17 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings of a year to a JSON file.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(year_data_dir(year))?;
+        let mut file = fs::File::create(get_file_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings of a year from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file(year: u16) -> Self {
+        fs::read_to_string(get_file_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +107,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,6 +116,38 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Compares every part timed in `self` with the same part in `baseline`.
+    /// Parts without a baseline are skipped.
+    pub fn compare(&self, baseline: &Self) -> Vec<PartDelta> {
+        let mut deltas = vec![];
+
+        for current in &self.data {
+            let Some(stored) = baseline.data.iter().find(|t| t.day == current.day) else {
+                continue;
+            };
+
+            for part in [1, 2] {
+                let (Some(baseline_nanos), Some(current_nanos)) =
+                    (stored.part_nanos(part), current.part_nanos(part))
+                else {
+                    continue;
+                };
+
+                if baseline_nanos > 0 {
+                    deltas.push(PartDelta {
+                        day: current.day,
+                        part,
+                        baseline_nanos,
+                        current_nanos,
+                        is_comparable: stored.statistic == current.statistic,
+                    });
+                }
+            }
+        }
+
+        deltas
+    }
+
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
@@ -69,6 +157,46 @@ impl Timings {
 
 /* -------------------------------------------------------------------------- */
 
+#[allow(clippy::cast_precision_loss)]
+fn memory_to_json(memory: Option<MemoryUsage>) -> JsonValue {
+    let Some(memory) = memory else {
+        return JsonValue::Null;
+    };
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert(
+        "peak_bytes".into(),
+        JsonValue::Number(memory.peak_bytes as f64),
+    );
+    map.insert(
+        "allocations".into(),
+        JsonValue::Number(memory.allocations as f64),
+    );
+    JsonValue::Object(map)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn memory_from_json(value: Option<&JsonValue>, key: &str) -> Result<Option<MemoryUsage>, String> {
+    let error = || format!("Expected timing.{key} to be null or an object of numbers.");
+
+    match value {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::Object(map)) => {
+            let number = |name: &str| {
+                map.get(name)
+                    .and_then(|v| v.get::<f64>())
+                    .map(|n| *n as u64)
+                    .ok_or_else(error)
+            };
+            Ok(Some(MemoryUsage {
+                peak_bytes: number("peak_bytes")?,
+                allocations: number("allocations")?,
+            }))
+        }
+        Some(_) => Err(error()),
+    }
+}
+
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
@@ -113,6 +241,10 @@ impl From<&Timing> for JsonValue {
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert(
+            "statistic".into(),
+            JsonValue::String(value.statistic.to_string()),
+        );
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
@@ -133,6 +265,18 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        #[allow(clippy::cast_precision_loss)]
+        let nanos_to_json =
+            |nanos: Option<u64>| nanos.map_or(JsonValue::Null, |n| JsonValue::Number(n as f64));
+        map.insert("part_1_nanos".into(), nanos_to_json(value.part_1_nanos));
+        map.insert("part_2_nanos".into(), nanos_to_json(value.part_2_nanos));
+
+        // timings without measured memory keep their previous shape.
+        if value.part_1_memory.is_some() || value.part_2_memory.is_some() {
+            map.insert("part_1_memory".into(), memory_to_json(value.part_1_memory));
+            map.insert("part_2_memory".into(), memory_to_json(value.part_2_memory));
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -161,16 +305,38 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // timings stored before exact execution times were recorded have none.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nanos = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::Number(n)) => Ok(Some(*n as u64)),
+            Some(_) => Err(format!("Expected timing.{key} to be null or a number.")),
+        };
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // timings stored before the statistic was recorded are means.
+        let statistic = match json.get("statistic") {
+            None => Statistic::Mean,
+            Some(v) => v
+                .get::<String>()
+                .and_then(|s| s.parse().ok())
+                .ok_or("Expected timing.statistic to be a known statistic.")?,
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_nanos: nanos("part_1_nanos")?,
+            part_2_nanos: nanos("part_2_nanos")?,
             total_nanos,
+            statistic,
+            part_1_memory: memory_from_json(json.get("part_1_memory"), "part_1_memory")?,
+            part_2_memory: memory_from_json(json.get("part_2_memory"), "part_2_memory")?,
         })
     }
 }
@@ -179,7 +345,7 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, template::runner::Statistic};
 
     use super::{Timing, Timings};
 
@@ -190,26 +356,44 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_nanos: Some(10_000_000),
+                    part_2_nanos: Some(20_000_000),
                     total_nanos: 3e+10,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_nanos: Some(30_000_000),
+                    part_2_nanos: Some(40_000_000),
                     total_nanos: 7e+10,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_nanos: Some(40_000_000),
+                    part_2_nanos: None,
                     total_nanos: 4e+10,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{runner::Statistic, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -219,8 +403,25 @@ mod tests {
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_1_nanos, None);
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.statistic, Statistic::Mean);
+        }
+
+        #[test]
+        fn handles_json_nanos() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.0µs", "part_2": null, "part_1_nanos": 1042, "part_2_nanos": null, "total_nanos": 1042 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].part_nanos(1), Some(1042));
+            assert_eq!(timings.data[0].part_nanos(2), None);
+        }
+
+        #[test]
+        fn handles_json_statistic() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000, "statistic": "min" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].statistic, Statistic::Min);
         }
 
         #[test]
@@ -247,9 +448,31 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::{alloc::MemoryUsage, timings::Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
+        #[test]
+        fn roundtrips_memory() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_2_memory = Some(MemoryUsage {
+                peak_bytes: 4096,
+                allocations: 7,
+            });
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].part_memory(1), None);
+            assert_eq!(
+                timings.data[0].part_memory(2),
+                Some(MemoryUsage {
+                    peak_bytes: 4096,
+                    allocations: 7,
+                })
+            );
+            assert_eq!(timings.data[1].part_memory(2), None);
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
@@ -271,7 +494,10 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                runner::Statistic,
+                timings::{Timing, Timings},
+            },
         };
 
         #[test]
@@ -281,11 +507,16 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_nanos: Some(1_000_000),
+                    part_2_nanos: Some(2_000_000),
                     total_nanos: 3_000_000_000_f64,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -295,11 +526,16 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_nanos: Some(1_000_000),
+                    part_2_nanos: None,
                     total_nanos: 1_000_000_000_f64,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -309,18 +545,26 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
                     total_nanos: 0.0,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                runner::Statistic,
+                timings::{Timing, Timings},
+            },
         };
 
         use super::get_mock_timings;
@@ -333,7 +577,12 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
                     total_nanos: 0_f64,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -353,7 +602,12 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
                     total_nanos: 0_f64,
+                    statistic: Statistic::Median,
+                    part_1_memory: None,
+                    part_2_memory: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -381,4 +635,114 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+    mod compare {
+        use crate::{
+            day,
+            template::{
+                runner::Statistic,
+                timings::{Timing, Timings},
+            },
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn compares_exact_durations() {
+            // both parts format as `1.0µs`, rounding must not be mistaken for a regression.
+            let timing = |nanos| Timing {
+                day: day!(1),
+                part_1: Some("1.0µs".into()),
+                part_2: None,
+                part_1_nanos: Some(nanos),
+                part_2_nanos: None,
+                total_nanos: 0.0,
+                statistic: Statistic::Median,
+                part_1_memory: None,
+                part_2_memory: None,
+            };
+            let baseline = Timings {
+                data: vec![timing(1_000)],
+            };
+            let current = Timings {
+                data: vec![timing(1_040)],
+            };
+
+            let deltas = current.compare(&baseline);
+            assert_eq!(deltas.len(), 1);
+            assert_eq!(deltas[0].current_nanos, 1_040);
+            assert_eq!(deltas[0].is_regression(10.0), false);
+            assert_eq!(get_mock_timings().data[2].part_nanos(2), None);
+        }
+
+        #[test]
+        fn skips_timings_without_exact_durations() {
+            let mut baseline = get_mock_timings();
+            baseline.data[0].part_1_nanos = None;
+
+            let deltas = get_mock_timings().compare(&baseline);
+            assert!(!deltas.iter().any(|d| d.day == day!(1) && d.part == 1));
+            assert!(deltas.iter().any(|d| d.day == day!(1) && d.part == 2));
+        }
+
+        #[test]
+        fn computes_deltas() {
+            let baseline = get_mock_timings();
+            let current = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(1),
+                        part_1: Some("12ms".into()),
+                        part_2: Some("10ms".into()),
+                        part_1_nanos: Some(12_000_000),
+                        part_2_nanos: Some(10_000_000),
+                        total_nanos: 0.0,
+                        statistic: Statistic::Median,
+                        part_1_memory: None,
+                        part_2_memory: None,
+                    },
+                    Timing {
+                        day: day!(3),
+                        part_1: Some("1ms".into()),
+                        part_2: None,
+                        part_1_nanos: Some(1_000_000),
+                        part_2_nanos: None,
+                        total_nanos: 0.0,
+                        statistic: Statistic::Median,
+                        part_1_memory: None,
+                        part_2_memory: None,
+                    },
+                ],
+            };
+
+            let deltas = current.compare(&baseline);
+            assert_eq!(deltas.len(), 2);
+            assert_eq!(deltas[0].change_percent(), 20.0);
+            assert_eq!(deltas[0].is_regression(10.0), true);
+            assert_eq!(deltas[0].is_regression(25.0), false);
+            assert_eq!(deltas[1].change_percent(), -50.0);
+            assert_eq!(deltas[1].is_regression(10.0), false);
+        }
+
+        #[test]
+        fn ignores_different_statistics() {
+            let baseline = get_mock_timings();
+            let current = Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    part_1: Some("60ms".into()),
+                    part_2: None,
+                    part_1_nanos: Some(60_000_000),
+                    part_2_nanos: None,
+                    total_nanos: 0.0,
+                    statistic: Statistic::Min,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                }],
+            };
+
+            let deltas = current.compare(&baseline);
+            assert_eq!(deltas[0].is_comparable, false);
+            assert_eq!(deltas[0].is_regression(10.0), false);
+        }
+    }
 }